@@ -1,12 +1,24 @@
 //! This crate provides functions for aligning addresses.
 //!
-//! [`Align`] is implemented for all unsigned integers and provides methods for:
+//! [`Align`] is implemented for all unsigned integers and their [`Wrapping`] and [`Saturating`] counterparts and provides methods for:
 //! * [`align_down`]
 //! * [`align_up`]
+//! * [`align_down_clamp`]
+//! * [`align_up_clamp`]
+//! * [`align_up_or_max`]
+//! * [`align_to_nearest`]
+//! * [`offset_to_aligned`]
+//! * [`alignment_order`]
 //! * [`is_aligned_to`]
 //!
 //! [`align_down`]: Align::align_down
 //! [`align_up`]: Align::align_up
+//! [`align_down_clamp`]: Align::align_down_clamp
+//! [`align_up_clamp`]: Align::align_up_clamp
+//! [`align_up_or_max`]: Align::align_up_or_max
+//! [`align_to_nearest`]: Align::align_to_nearest
+//! [`offset_to_aligned`]: Align::offset_to_aligned
+//! [`alignment_order`]: Align::alignment_order
 //! [`is_aligned_to`]: Align::is_aligned_to
 //!
 //! This crate is based on work from the [`x86_64`] crate, but is available for all architectures and all unsigned integer types.
@@ -20,12 +32,147 @@
 //!
 //! assert_eq!(123_u64.align_up(2), 124);
 //! ```
+//!
+//! The free functions are `const` and can be used wherever a constant is expected, such as array lengths and const generic arguments:
+//!
+//! ```
+//! use align_address::usize_align_up;
+//!
+//! const SIZE: usize = 1500;
+//! const PAGE_ALIGN: usize = 4096;
+//!
+//! struct Frame {
+//!     bytes: [u8; usize_align_up(SIZE, PAGE_ALIGN)],
+//! }
+//!
+//! struct Buffer<const N: usize>([u8; N]);
+//!
+//! type PaddedBuffer = Buffer<{ usize_align_up(SIZE, 64) }>;
+//!
+//! assert_eq!(core::mem::size_of::<Frame>(), 4096);
+//! assert_eq!(core::mem::size_of::<PaddedBuffer>(), 1536);
+//! ```
+//!
+//! In constants, an overflow while aligning is a compile-time error ("attempt to add with overflow") instead of a panic at runtime.
+//! While this compiles:
+//!
+//! ```
+//! use align_address::usize_align_up;
+//!
+//! const BAR: usize = usize_align_up(100, 4096);
+//!
+//! assert_eq!(BAR, 4096);
+//! ```
+//!
+//! this fails to compile:
+//!
+//! ```compile_fail,E0080
+//! use align_address::usize_align_up;
+//!
+//! const FOO: usize = usize_align_up(usize::MAX, 2);
+//!
+//! assert_eq!(FOO, 0);
+//! ```
+//!
+//! With `_checked_const` functions such as [`usize_align_up_checked_const`], the alignment is a const generic parameter and is checked at compile time, even if the address is only known at runtime:
+//!
+//! ```compile_fail,E0080
+//! use align_address::usize_align_up_checked_const;
+//!
+//! fn align_up_to_three(addr: usize) -> usize {
+//!     usize_align_up_checked_const::<3>(addr)
+//! }
+//!
+//! assert_eq!(align_up_to_three(4), 6);
+//! ```
+//!
+//! The free functions for each type are also available without the type prefix in a module named after the type:
+//!
+//! ```
+//! use align_address::u64::*;
+//!
+//! assert_eq!(align_up(123, 2), 124);
+//! assert_eq!(align_down(123, 2), 122);
+//! ```
+//!
+//! ## Embedded
+//!
+//! Placing a DMA buffer at the next boundary supported by the DMA controller:
+//!
+//! ```
+//! use align_address::Align;
+//!
+//! const DMA_ALIGN: u32 = 32;
+//!
+//! let heap_start: u32 = 0x2000_0104;
+//! let dma_buffer = heap_start.align_up(DMA_ALIGN);
+//! assert_eq!(dma_buffer, 0x2000_0120);
+//! ```
+//!
+//! Checking that a peripheral register address is word-aligned before accessing it:
+//!
+//! ```
+//! use align_address::u32_is_aligned_to;
+//!
+//! const GPIOA_ODR: u32 = 0x4002_0014;
+//! const WORD: u32 = 4;
+//!
+//! assert!(u32_is_aligned_to(GPIOA_ODR, WORD));
+//! assert!(!u32_is_aligned_to(GPIOA_ODR + 1, WORD));
+//! ```
+//!
+//! Computing the padding after a protocol header so the payload starts on a word boundary:
+//!
+//! ```
+//! use align_address::usize_align_up;
+//!
+//! const HEADER_LEN: usize = 14;
+//! const PAYLOAD_OFFSET: usize = usize_align_up(HEADER_LEN, 4);
+//! const PADDING: usize = PAYLOAD_OFFSET - HEADER_LEN;
+//!
+//! assert_eq!(PAYLOAD_OFFSET, 16);
+//! assert_eq!(PADDING, 2);
+//! ```
+//!
+//! # Features
+//!
+//! * `always-validate` (default): Validate that alignments are powers of two even if debug assertions are disabled.
+//!   Without this feature, passing an alignment that is not a power of two is only caught in debug builds and produces unspecified results otherwise.
+//! * `alloc`: Functions that allocate, such as `usize_align_pad_bytes`.
+//! * `crypto`: Alignment helpers for cryptographic word sizes in the `crypto` module.
+//! * `isa-x86_64`, `isa-aarch64`, `isa-riscv`: Page sizes and page alignment for these architectures in the `isa` module.
+//! * `ssz`: Alignment helpers for SSZ encoding in the `ssz` module.
 
 #![no_std]
 #![forbid(unsafe_code)]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::mem;
+use core::num::{Saturating, Wrapping};
+
+/// Asserts that the argument is a power of two.
+///
+/// Without the `always-validate` feature, this is only checked if debug assertions are enabled.
+macro_rules! assert_power_of_two {
+    ($align:expr, $msg:literal) => {
+        #[cfg(feature = "always-validate")]
+        assert!($align.is_power_of_two(), $msg);
+        #[cfg(not(feature = "always-validate"))]
+        debug_assert!($align.is_power_of_two(), $msg);
+    };
+}
+
 /// An adress that can be aligned.
 pub trait Align<A = Self>: Copy + PartialEq {
+    /// The natural alignment of the type in memory.
+    ///
+    /// This is [`mem::align_of`] of the underlying integer type.
+    const NATURAL_ALIGN: Self;
+
     /// Align address downwards.
     ///
     /// Returns the greatest `x` with alignment `align` so that `x <= addr`.
@@ -38,8 +185,57 @@ pub trait Align<A = Self>: Copy + PartialEq {
     /// Returns the smallest `x` with alignment `align` so that `x >= addr`.
     ///
     /// Panics if the alignment is not a power of two or if an overflow occurs.
+    /// Implementations may define other behavior on overflow, such as the implementations for [`Wrapping`] and [`Saturating`].
     fn align_up(self, align: A) -> Self;
 
+    /// Align address upwards, saturating at the numeric bounds.
+    ///
+    /// Returns the smallest `x` with alignment `align` so that `x >= addr`,
+    /// or the maximum value of the type if an overflow occurs.
+    ///
+    /// Panics if the alignment is not a power of two.
+    fn align_up_or_max(self, align: A) -> Self;
+
+    /// Align address to the nearest boundary.
+    ///
+    /// Returns whichever of [`align_down`] and [`align_up`] is closer to `addr`.
+    /// Ties are rounded upwards.
+    ///
+    /// Panics if the alignment is not a power of two or if an overflow occurs.
+    /// Implementations may define other behavior on overflow, such as the implementations for [`Wrapping`] and [`Saturating`].
+    ///
+    /// [`align_down`]: Self::align_down
+    /// [`align_up`]: Self::align_up
+    fn align_to_nearest(self, align: A) -> Self;
+
+    /// Computes the offset from the address to the next boundary.
+    ///
+    /// Returns the smallest `x` so that `addr + x` has alignment `align`.
+    /// Unlike aligning upwards and subtracting, this never overflows.
+    ///
+    /// Panics if the alignment is not a power of two.
+    fn offset_to_aligned(self, align: A) -> Self;
+
+    /// Computes the alignment order of the address.
+    ///
+    /// Returns the greatest `n` so that the address has alignment `2^n`.
+    /// Since zero has every alignment, this returns the number of bits of the type for zero.
+    fn alignment_order(self) -> u32;
+
+    /// Align address downwards, but not below `min`.
+    ///
+    /// Returns the greatest `x` with alignment `align` so that `x <= addr`, or `min` if `x < min`.
+    ///
+    /// Panics if the alignment is not a power of two.
+    fn align_down_clamp(self, align: A, min: Self) -> Self;
+
+    /// Align address upwards, but not above `max`.
+    ///
+    /// Returns the smallest `x` with alignment `align` so that `x >= addr`, or `max` if `x > max` or an overflow occurs.
+    ///
+    /// Panics if the alignment is not a power of two.
+    fn align_up_clamp(self, align: A, max: Self) -> Self;
+
     /// Checks whether the address has the demanded alignment.
     #[allow(clippy::wrong_self_convention)]
     #[inline]
@@ -49,7 +245,19 @@ pub trait Align<A = Self>: Copy + PartialEq {
 }
 
 macro_rules! align_impl {
-    ($u:ty, $align_down:ident, $align_up:ident, $is_aligned_to:ident) => {
+    (
+        $u:ty,
+        $align_down:ident,
+        $align_up:ident,
+        $checked_align_up:ident,
+        $saturating_align_up:ident,
+        $is_aligned_to:ident,
+        $align_to_nearest:ident,
+        $offset_to_aligned:ident,
+        $alignment_order:ident,
+        $align_down_clamp:ident,
+        $align_up_clamp:ident
+    ) => {
         /// Align address downwards.
         ///
         /// Returns the greatest `x` with alignment `align` so that `x <= addr`.
@@ -60,7 +268,7 @@ macro_rules! align_impl {
         // Adapted from `x86_64`
         #[inline]
         pub const fn $align_down(addr: $u, align: $u) -> $u {
-            assert!(align.is_power_of_two(), "`align` must be a power of two");
+            assert_power_of_two!(align, "`align` must be a power of two");
             addr & !(align - 1)
         }
 
@@ -74,17 +282,36 @@ macro_rules! align_impl {
         // Adapted from `x86_64`
         #[inline]
         pub const fn $align_up(addr: $u, align: $u) -> $u {
-            assert!(align.is_power_of_two(), "`align` must be a power of two");
+            // FIXME: Replace with .expect, once `Option::expect` is const.
+            if let Some(aligned) = $checked_align_up(addr, align) {
+                aligned
+            } else {
+                panic!("attempt to add with overflow")
+            }
+        }
+
+        /// Checked address alignment upwards.
+        ///
+        /// Returns the smallest `x` with alignment `align` so that `x >= addr`,
+        /// or `None` if an overflow occurs.
+        ///
+        /// Panics if the alignment is not a power of two.
+        #[inline]
+        pub const fn $checked_align_up(addr: $u, align: $u) -> Option<$u> {
+            assert_power_of_two!(align, "`align` must be a power of two");
             let align_mask = align - 1;
-            if addr & align_mask == 0 {
-                addr // already aligned
+            // Adding the mask overflows exactly if the aligned address does not fit.
+            // With a constant `align`, this compiles to branchless code, where the carry of the addition becomes the discriminant:
+            //
+            // ```asm
+            // sub rdi, -4095
+            // setb al
+            // and rdi, -4096
+            // ```
+            if let Some(addr) = addr.checked_add(align_mask) {
+                Some(addr & !align_mask)
             } else {
-                // FIXME: Replace with .expect, once `Option::expect` is const.
-                if let Some(aligned) = (addr | align_mask).checked_add(1) {
-                    aligned
-                } else {
-                    panic!("attempt to add with overflow")
-                }
+                None
             }
         }
 
@@ -96,7 +323,88 @@ macro_rules! align_impl {
             $align_down(addr, align) == addr
         }
 
+        /// Align address to the nearest boundary.
+        ///
+        /// Returns whichever of [`align_down`] and [`align_up`] is closer to `addr`.
+        /// Ties are rounded upwards.
+        ///
+        /// Panics if the alignment is not a power of two or if an overflow occurs.
+        ///
+        /// This is a `const` version of [`Align::align_to_nearest`].
+        ///
+        #[doc = concat!("[`align_down`]: ", stringify!($align_down))]
+        #[doc = concat!("[`align_up`]: ", stringify!($align_up))]
+        #[inline]
+        pub const fn $align_to_nearest(addr: $u, align: $u) -> $u {
+            let down = $align_down(addr, align);
+            if addr - down < align / 2 {
+                down
+            } else {
+                $align_up(addr, align)
+            }
+        }
+
+        /// Computes the offset from the address to the next boundary.
+        ///
+        /// Returns the smallest `x` so that `addr + x` has alignment `align`.
+        /// Unlike aligning upwards and subtracting, this never overflows.
+        ///
+        /// Panics if the alignment is not a power of two.
+        ///
+        /// This is a `const` version of [`Align::offset_to_aligned`].
+        #[inline]
+        pub const fn $offset_to_aligned(addr: $u, align: $u) -> $u {
+            assert_power_of_two!(align, "`align` must be a power of two");
+            let align_mask = align - 1;
+            (align - (addr & align_mask)) & align_mask
+        }
+
+        /// Computes the alignment order of the address.
+        ///
+        /// Returns the greatest `n` so that the address has alignment `2^n`.
+        #[doc = concat!("Since zero has every alignment, this returns `", stringify!($u), "::BITS` for zero.")]
+        ///
+        /// This is a `const` version of [`Align::alignment_order`].
+        #[inline]
+        pub const fn $alignment_order(addr: $u) -> u32 {
+            addr.trailing_zeros()
+        }
+
+        /// Align address downwards, but not below `min`.
+        ///
+        /// Returns the greatest `x` with alignment `align` so that `x <= addr`, or `min` if `x < min`.
+        ///
+        /// Panics if the alignment is not a power of two.
+        ///
+        /// This is a `const` version of [`Align::align_down_clamp`].
+        #[inline]
+        pub const fn $align_down_clamp(addr: $u, align: $u, min: $u) -> $u {
+            let aligned = $align_down(addr, align);
+            if aligned < min {
+                min
+            } else {
+                aligned
+            }
+        }
+
+        /// Align address upwards, but not above `max`.
+        ///
+        /// Returns the smallest `x` with alignment `align` so that `x >= addr`, or `max` if `x > max` or an overflow occurs.
+        ///
+        /// Panics if the alignment is not a power of two.
+        ///
+        /// This is a `const` version of [`Align::align_up_clamp`].
+        #[inline]
+        pub const fn $align_up_clamp(addr: $u, align: $u, max: $u) -> $u {
+            match $checked_align_up(addr, align) {
+                Some(aligned) if aligned <= max => aligned,
+                _ => max,
+            }
+        }
+
         impl Align for $u {
+            const NATURAL_ALIGN: Self = mem::align_of::<$u>() as $u;
+
             #[inline]
             fn align_down(self, align: Self) -> Self {
                 $align_down(self, align)
@@ -106,64 +414,4807 @@ macro_rules! align_impl {
             fn align_up(self, align: Self) -> Self {
                 $align_up(self, align)
             }
+
+            #[inline]
+            fn align_up_or_max(self, align: Self) -> Self {
+                $saturating_align_up(self, align)
+            }
+
+            #[inline]
+            fn align_to_nearest(self, align: Self) -> Self {
+                $align_to_nearest(self, align)
+            }
+
+            #[inline]
+            fn offset_to_aligned(self, align: Self) -> Self {
+                $offset_to_aligned(self, align)
+            }
+
+            #[inline]
+            fn alignment_order(self) -> u32 {
+                $alignment_order(self)
+            }
+
+            #[inline]
+            fn align_down_clamp(self, align: Self, min: Self) -> Self {
+                $align_down_clamp(self, align, min)
+            }
+
+            #[inline]
+            fn align_up_clamp(self, align: Self, max: Self) -> Self {
+                $align_up_clamp(self, align, max)
+            }
         }
     };
 }
 
-align_impl!(u8, u8_align_down, u8_align_up, u8_is_aligned_to);
-align_impl!(u16, u16_align_down, u16_align_up, u16_is_aligned_to);
-align_impl!(u32, u32_align_down, u32_align_up, u32_is_aligned_to);
-align_impl!(u64, u64_align_down, u64_align_up, u64_is_aligned_to);
-align_impl!(u128, u128_align_down, u128_align_up, u128_is_aligned_to);
-align_impl!(usize, usize_align_down, usize_align_up, usize_is_aligned_to);
+align_impl!(
+    u8,
+    u8_align_down,
+    u8_align_up,
+    u8_checked_align_up,
+    u8_saturating_align_up,
+    u8_is_aligned_to,
+    u8_align_to_nearest,
+    u8_offset_to_aligned,
+    u8_alignment_order,
+    u8_align_down_clamp,
+    u8_align_up_clamp
+);
+align_impl!(
+    u16,
+    u16_align_down,
+    u16_align_up,
+    u16_checked_align_up,
+    u16_saturating_align_up,
+    u16_is_aligned_to,
+    u16_align_to_nearest,
+    u16_offset_to_aligned,
+    u16_alignment_order,
+    u16_align_down_clamp,
+    u16_align_up_clamp
+);
+align_impl!(
+    u32,
+    u32_align_down,
+    u32_align_up,
+    u32_checked_align_up,
+    u32_saturating_align_up,
+    u32_is_aligned_to,
+    u32_align_to_nearest,
+    u32_offset_to_aligned,
+    u32_alignment_order,
+    u32_align_down_clamp,
+    u32_align_up_clamp
+);
+align_impl!(
+    u64,
+    u64_align_down,
+    u64_align_up,
+    u64_checked_align_up,
+    u64_saturating_align_up,
+    u64_is_aligned_to,
+    u64_align_to_nearest,
+    u64_offset_to_aligned,
+    u64_alignment_order,
+    u64_align_down_clamp,
+    u64_align_up_clamp
+);
+align_impl!(
+    u128,
+    u128_align_down,
+    u128_align_up,
+    u128_checked_align_up,
+    u128_saturating_align_up,
+    u128_is_aligned_to,
+    u128_align_to_nearest,
+    u128_offset_to_aligned,
+    u128_alignment_order,
+    u128_align_down_clamp,
+    u128_align_up_clamp
+);
+align_impl!(
+    usize,
+    usize_align_down,
+    usize_align_up,
+    usize_checked_align_up,
+    usize_saturating_align_up,
+    usize_is_aligned_to,
+    usize_align_to_nearest,
+    usize_offset_to_aligned,
+    usize_alignment_order,
+    usize_align_down_clamp,
+    usize_align_up_clamp
+);
 
-// Adapted from `x86_64`
-#[cfg(test)]
-mod tests {
-    use super::*;
+macro_rules! align_skip_impl {
+    (
+        $u:ty,
+        $align_down:ident,
+        $checked_align_up:ident,
+        $align_up_to_next_aligned_after:ident,
+        $align_down_to_prev_aligned_before:ident
+    ) => {
+        /// Align address upwards, skipping everything up to and including `after`.
+        ///
+        /// Returns the smallest `x` with alignment `align` so that `x >= addr` and `x > after`,
+        /// or `None` if an overflow occurs.
+        ///
+        /// Panics if the alignment is not a power of two.
+        #[inline]
+        pub const fn $align_up_to_next_aligned_after(addr: $u, align: $u, after: $u) -> Option<$u> {
+            let Some(addr) = $checked_align_up(addr, align) else {
+                return None;
+            };
+            let Some(after) = after.checked_add(1) else {
+                return None;
+            };
+            let Some(after) = $checked_align_up(after, align) else {
+                return None;
+            };
+            if addr > after {
+                Some(addr)
+            } else {
+                Some(after)
+            }
+        }
 
-    macro_rules! test_align_up_impl {
-        ($u:ty, $align_up:ident, $test_align_up:ident) => {
-            #[test]
-            fn $test_align_up() {
-                // align 1
-                assert_eq!($align_up(0, 1), 0);
-                assert_eq!($align_up(123, 1), 123);
-                assert_eq!($align_up(<$u>::MAX, 1), <$u>::MAX);
-                // align 2
-                assert_eq!($align_up(0, 2), 0);
-                assert_eq!($align_up(123, 2), 124);
-                assert_eq!($align_up(<$u>::MAX - 1, 2), <$u>::MAX - 1);
-                // address 0
-                assert_eq!($align_up(0, 128), 0);
-                assert_eq!($align_up(0, 1), 0);
-                assert_eq!($align_up(0, 2), 0);
-                assert_eq!($align_up(0, <$u>::MAX & 1 << (<$u>::BITS - 1)), 0);
+        /// Align address downwards, skipping everything from `before` upwards.
+        ///
+        /// Returns the greatest `x` with alignment `align` so that `x <= addr` and `x < before`,
+        /// or `None` if `before` is zero.
+        ///
+        /// Panics if the alignment is not a power of two.
+        #[inline]
+        pub const fn $align_down_to_prev_aligned_before(
+            addr: $u,
+            align: $u,
+            before: $u,
+        ) -> Option<$u> {
+            let addr = $align_down(addr, align);
+            let Some(before) = before.checked_sub(1) else {
+                return None;
+            };
+            let before = $align_down(before, align);
+            if addr < before {
+                Some(addr)
+            } else {
+                Some(before)
             }
-        };
-    }
+        }
+    };
+}
 
-    test_align_up_impl!(u8, u8_align_up, test_u8_align_up);
-    test_align_up_impl!(u16, u16_align_up, test_u16_align_up);
-    test_align_up_impl!(u32, u32_align_up, test_u32_align_up);
-    test_align_up_impl!(u64, u64_align_up, test_u64_align_up);
-    test_align_up_impl!(u128, u128_align_up, test_u128_align_up);
-    test_align_up_impl!(usize, usize_align_up, test_usize_align_up);
+align_skip_impl!(
+    u8,
+    u8_align_down,
+    u8_checked_align_up,
+    u8_align_up_to_next_aligned_after,
+    u8_align_down_to_prev_aligned_before
+);
+align_skip_impl!(
+    u16,
+    u16_align_down,
+    u16_checked_align_up,
+    u16_align_up_to_next_aligned_after,
+    u16_align_down_to_prev_aligned_before
+);
+align_skip_impl!(
+    u32,
+    u32_align_down,
+    u32_checked_align_up,
+    u32_align_up_to_next_aligned_after,
+    u32_align_down_to_prev_aligned_before
+);
+align_skip_impl!(
+    u64,
+    u64_align_down,
+    u64_checked_align_up,
+    u64_align_up_to_next_aligned_after,
+    u64_align_down_to_prev_aligned_before
+);
+align_skip_impl!(
+    u128,
+    u128_align_down,
+    u128_checked_align_up,
+    u128_align_up_to_next_aligned_after,
+    u128_align_down_to_prev_aligned_before
+);
+align_skip_impl!(
+    usize,
+    usize_align_down,
+    usize_checked_align_up,
+    usize_align_up_to_next_aligned_after,
+    usize_align_down_to_prev_aligned_before
+);
 
-    macro_rules! test_align_up_overflow_impl {
-        ($u:ty, $test_align_up_overflow:ident, $two:expr) => {
-            #[test]
-            #[should_panic]
-            fn $test_align_up_overflow() {
-                <$u>::MAX.align_up($two);
+macro_rules! align_intersection_impl {
+    ($u:ty, $align_intersection:ident) => {
+        /// Computes the largest alignment that all addresses have.
+        ///
+        /// Returns the greatest power of two `x` so that every address in `addrs` is aligned to `x`.
+        /// If `addrs` is empty, returns `1`.
+        /// If all addresses are zero, returns the greatest power of two representable by the type.
+        #[inline]
+        pub const fn $align_intersection(addrs: &[$u]) -> $u {
+            if addrs.is_empty() {
+                return 1;
             }
-        };
-    }
+            let mut acc = 0;
+            let mut i = 0;
+            while i < addrs.len() {
+                acc |= addrs[i];
+                i += 1;
+            }
+            if acc == 0 {
+                1 << (<$u>::BITS - 1)
+            } else {
+                1 << acc.trailing_zeros()
+            }
+        }
+    };
+}
 
-    test_align_up_overflow_impl!(u8, test_u8_align_up_overflow, 2);
-    test_align_up_overflow_impl!(u16, test_u16_align_up_overflow, 2);
-    test_align_up_overflow_impl!(u32, test_u32_align_up_overflow, 2);
-    test_align_up_overflow_impl!(u64, test_u64_align_up_overflow, 2);
-    test_align_up_overflow_impl!(u128, test_u128_align_up_overflow, 2);
-    test_align_up_overflow_impl!(usize, test_usize_align_up_overflow, 2);
+align_intersection_impl!(u8, u8_align_intersection);
+align_intersection_impl!(u16, u16_align_intersection);
+align_intersection_impl!(u32, u32_align_intersection);
+align_intersection_impl!(u64, u64_align_intersection);
+align_intersection_impl!(u128, u128_align_intersection);
+align_intersection_impl!(usize, usize_align_intersection);
+
+macro_rules! align_scale_impl {
+    (
+        $u:ty,
+        $align_down:ident,
+        $checked_align_up:ident,
+        $align_down_halved:ident,
+        $align_up_doubled:ident
+    ) => {
+        /// Align address downwards to half the alignment.
+        ///
+        /// Returns the greatest `x` with alignment `align / 2` so that `x <= addr`.
+        ///
+        /// Panics if the alignment is not a power of two or if it is less than two.
+        #[inline]
+        pub const fn $align_down_halved(addr: $u, align: $u) -> $u {
+            assert_power_of_two!(align, "`align` must be a power of two");
+            assert!(align >= 2, "`align` must be at least two");
+            $align_down(addr, align / 2)
+        }
+
+        /// Align address upwards to double the alignment.
+        ///
+        /// Returns the smallest `x` with alignment `align * 2` so that `x >= addr`,
+        /// or `None` if an overflow occurs.
+        ///
+        /// Panics if the alignment is not a power of two.
+        #[inline]
+        pub const fn $align_up_doubled(addr: $u, align: $u) -> Option<$u> {
+            assert_power_of_two!(align, "`align` must be a power of two");
+            let Some(align) = align.checked_mul(2) else {
+                return None;
+            };
+            $checked_align_up(addr, align)
+        }
+    };
+}
+
+align_scale_impl!(
+    u8,
+    u8_align_down,
+    u8_checked_align_up,
+    u8_align_down_halved,
+    u8_align_up_doubled
+);
+align_scale_impl!(
+    u16,
+    u16_align_down,
+    u16_checked_align_up,
+    u16_align_down_halved,
+    u16_align_up_doubled
+);
+align_scale_impl!(
+    u32,
+    u32_align_down,
+    u32_checked_align_up,
+    u32_align_down_halved,
+    u32_align_up_doubled
+);
+align_scale_impl!(
+    u64,
+    u64_align_down,
+    u64_checked_align_up,
+    u64_align_down_halved,
+    u64_align_up_doubled
+);
+align_scale_impl!(
+    u128,
+    u128_align_down,
+    u128_checked_align_up,
+    u128_align_down_halved,
+    u128_align_up_doubled
+);
+align_scale_impl!(
+    usize,
+    usize_align_down,
+    usize_checked_align_up,
+    usize_align_down_halved,
+    usize_align_up_doubled
+);
+
+macro_rules! align_wrapper_impl {
+    (
+        $u:ty,
+        $align_down:ident,
+        $checked_align_up:ident,
+        $wrapping_align_up:ident,
+        $saturating_align_up:ident,
+        $offset_to_aligned:ident
+    ) => {
+        /// Wrapping address alignment upwards.
+        ///
+        /// Returns the smallest `x` with alignment `align` so that `x >= addr`,
+        /// wrapping around at the boundary of the type.
+        ///
+        /// Panics if the alignment is not a power of two.
+        #[inline]
+        pub const fn $wrapping_align_up(addr: $u, align: $u) -> $u {
+            assert_power_of_two!(align, "`align` must be a power of two");
+            let align_mask = align - 1;
+            // With a constant `align`, this compiles to a branchless `lea` and `and`:
+            //
+            // ```asm
+            // lea rax, [rdi + 4095]
+            // and rax, -4096
+            // ```
+            addr.wrapping_add(align_mask) & !align_mask
+        }
+
+        /// Saturating address alignment upwards.
+        ///
+        /// Returns the smallest `x` with alignment `align` so that `x >= addr`,
+        /// saturating at the numeric bounds instead of overflowing.
+        ///
+        /// Panics if the alignment is not a power of two.
+        ///
+        /// This is a `const` version of [`Align::align_up_or_max`].
+        #[inline]
+        pub const fn $saturating_align_up(addr: $u, align: $u) -> $u {
+            if let Some(aligned) = $checked_align_up(addr, align) {
+                aligned
+            } else {
+                <$u>::MAX
+            }
+        }
+
+        /// Aligning upwards wraps around on overflow.
+        ///
+        /// If aligning upwards overflows, [`align_up`] and [`align_to_nearest`] return `0` instead of panicking, which is less than the address.
+        ///
+        /// [`align_up`]: Align::align_up
+        /// [`align_to_nearest`]: Align::align_to_nearest
+        impl Align for Wrapping<$u> {
+            const NATURAL_ALIGN: Self = Wrapping(<$u>::NATURAL_ALIGN);
+
+            #[inline]
+            fn align_down(self, align: Self) -> Self {
+                Wrapping($align_down(self.0, align.0))
+            }
+
+            #[inline]
+            fn align_up(self, align: Self) -> Self {
+                Wrapping($wrapping_align_up(self.0, align.0))
+            }
+
+            #[inline]
+            fn align_up_or_max(self, align: Self) -> Self {
+                Wrapping($saturating_align_up(self.0, align.0))
+            }
+
+            #[inline]
+            fn align_to_nearest(self, align: Self) -> Self {
+                let down = self.align_down(align);
+                if self.0 - down.0 < align.0 / 2 {
+                    down
+                } else {
+                    self.align_up(align)
+                }
+            }
+
+            #[inline]
+            fn offset_to_aligned(self, align: Self) -> Self {
+                Wrapping($offset_to_aligned(self.0, align.0))
+            }
+
+            #[inline]
+            fn alignment_order(self) -> u32 {
+                self.0.alignment_order()
+            }
+
+            #[inline]
+            fn align_down_clamp(self, align: Self, min: Self) -> Self {
+                Wrapping(self.0.align_down_clamp(align.0, min.0))
+            }
+
+            #[inline]
+            fn align_up_clamp(self, align: Self, max: Self) -> Self {
+                Wrapping(self.0.align_up_clamp(align.0, max.0))
+            }
+        }
+
+        /// Aligning upwards saturates on overflow.
+        ///
+        /// If aligning upwards overflows, [`align_up`] and [`align_to_nearest`] return the maximum value of the type instead of panicking, which does not have the alignment.
+        ///
+        /// [`align_up`]: Align::align_up
+        /// [`align_to_nearest`]: Align::align_to_nearest
+        impl Align for Saturating<$u> {
+            const NATURAL_ALIGN: Self = Saturating(<$u>::NATURAL_ALIGN);
+
+            #[inline]
+            fn align_down(self, align: Self) -> Self {
+                Saturating($align_down(self.0, align.0))
+            }
+
+            #[inline]
+            fn align_up(self, align: Self) -> Self {
+                Saturating($saturating_align_up(self.0, align.0))
+            }
+
+            #[inline]
+            fn align_up_or_max(self, align: Self) -> Self {
+                Saturating($saturating_align_up(self.0, align.0))
+            }
+
+            #[inline]
+            fn align_to_nearest(self, align: Self) -> Self {
+                let down = self.align_down(align);
+                if self.0 - down.0 < align.0 / 2 {
+                    down
+                } else {
+                    self.align_up(align)
+                }
+            }
+
+            #[inline]
+            fn offset_to_aligned(self, align: Self) -> Self {
+                Saturating($offset_to_aligned(self.0, align.0))
+            }
+
+            #[inline]
+            fn alignment_order(self) -> u32 {
+                self.0.alignment_order()
+            }
+
+            #[inline]
+            fn align_down_clamp(self, align: Self, min: Self) -> Self {
+                Saturating(self.0.align_down_clamp(align.0, min.0))
+            }
+
+            #[inline]
+            fn align_up_clamp(self, align: Self, max: Self) -> Self {
+                Saturating(self.0.align_up_clamp(align.0, max.0))
+            }
+        }
+    };
+}
+
+align_wrapper_impl!(
+    u8,
+    u8_align_down,
+    u8_checked_align_up,
+    u8_wrapping_align_up,
+    u8_saturating_align_up,
+    u8_offset_to_aligned
+);
+align_wrapper_impl!(
+    u16,
+    u16_align_down,
+    u16_checked_align_up,
+    u16_wrapping_align_up,
+    u16_saturating_align_up,
+    u16_offset_to_aligned
+);
+align_wrapper_impl!(
+    u32,
+    u32_align_down,
+    u32_checked_align_up,
+    u32_wrapping_align_up,
+    u32_saturating_align_up,
+    u32_offset_to_aligned
+);
+align_wrapper_impl!(
+    u64,
+    u64_align_down,
+    u64_checked_align_up,
+    u64_wrapping_align_up,
+    u64_saturating_align_up,
+    u64_offset_to_aligned
+);
+align_wrapper_impl!(
+    u128,
+    u128_align_down,
+    u128_checked_align_up,
+    u128_wrapping_align_up,
+    u128_saturating_align_up,
+    u128_offset_to_aligned
+);
+align_wrapper_impl!(
+    usize,
+    usize_align_down,
+    usize_checked_align_up,
+    usize_wrapping_align_up,
+    usize_saturating_align_up,
+    usize_offset_to_aligned
+);
+
+/// Align a 64-bit address downwards and narrow it to 32 bits.
+///
+/// Returns the greatest `x` with alignment `align` so that `x <= addr`,
+/// or `None` if `x` does not fit into a `u32`.
+///
+/// Panics if the alignment is not a power of two.
+#[inline]
+pub const fn u64_align_down_truncate_u32(addr: u64, align: u32) -> Option<u32> {
+    let aligned = u64_align_down(addr, align as u64);
+    if aligned <= u32::MAX as u64 {
+        Some(aligned as u32)
+    } else {
+        None
+    }
+}
+
+/// Align a 64-bit address upwards and narrow it to 32 bits.
+///
+/// Returns the smallest `x` with alignment `align` so that `x >= addr`,
+/// or `None` if `x` does not fit into a `u32`.
+///
+/// Panics if the alignment is not a power of two.
+#[inline]
+pub const fn u64_align_up_truncate_u32(addr: u64, align: u32) -> Option<u32> {
+    let Some(aligned) = u64_checked_align_up(addr, align as u64) else {
+        return None;
+    };
+    if aligned <= u32::MAX as u64 {
+        Some(aligned as u32)
+    } else {
+        None
+    }
+}
+
+macro_rules! align_offset_impl {
+    (
+        $u:ty,
+        $align_down:ident,
+        $checked_align_up:ident,
+        $align_up_add:ident,
+        $align_down_sub:ident
+    ) => {
+        /// Add an offset to the address and align the result upwards.
+        ///
+        /// Returns the smallest `x` with alignment `align` so that `x >= addr + offset`,
+        /// or `None` if an overflow occurs.
+        ///
+        /// Panics if the alignment is not a power of two.
+        #[inline]
+        pub const fn $align_up_add(addr: $u, offset: $u, align: $u) -> Option<$u> {
+            let Some(addr) = addr.checked_add(offset) else {
+                return None;
+            };
+            $checked_align_up(addr, align)
+        }
+
+        /// Subtract an offset from the address and align the result downwards.
+        ///
+        /// Returns the greatest `x` with alignment `align` so that `x <= addr - offset`,
+        /// or `None` if an underflow occurs.
+        ///
+        /// Panics if the alignment is not a power of two.
+        #[inline]
+        pub const fn $align_down_sub(addr: $u, offset: $u, align: $u) -> Option<$u> {
+            let Some(addr) = addr.checked_sub(offset) else {
+                return None;
+            };
+            Some($align_down(addr, align))
+        }
+    };
+}
+
+align_offset_impl!(
+    u8,
+    u8_align_down,
+    u8_checked_align_up,
+    u8_align_up_add,
+    u8_align_down_sub
+);
+align_offset_impl!(
+    u16,
+    u16_align_down,
+    u16_checked_align_up,
+    u16_align_up_add,
+    u16_align_down_sub
+);
+align_offset_impl!(
+    u32,
+    u32_align_down,
+    u32_checked_align_up,
+    u32_align_up_add,
+    u32_align_down_sub
+);
+align_offset_impl!(
+    u64,
+    u64_align_down,
+    u64_checked_align_up,
+    u64_align_up_add,
+    u64_align_down_sub
+);
+align_offset_impl!(
+    u128,
+    u128_align_down,
+    u128_checked_align_up,
+    u128_align_up_add,
+    u128_align_down_sub
+);
+align_offset_impl!(
+    usize,
+    usize_align_down,
+    usize_checked_align_up,
+    usize_align_up_add,
+    usize_align_down_sub
+);
+
+/// Page sizes and page alignment for specific instruction set architectures.
+#[cfg(any(feature = "isa-x86_64", feature = "isa-aarch64", feature = "isa-riscv"))]
+pub mod isa {
+    macro_rules! isa_impl {
+        ($page_size:expr, $large_page_size:expr, $huge_page_size:expr) => {
+            use crate::{usize_align_down, usize_align_up};
+
+            /// The size of a base page in bytes.
+            pub const PAGE_SIZE: usize = $page_size;
+
+            /// The size of a large page in bytes.
+            pub const LARGE_PAGE_SIZE: usize = $large_page_size;
+
+            /// The size of a huge page in bytes.
+            pub const HUGE_PAGE_SIZE: usize = $huge_page_size;
+
+            /// Align address downwards to [`PAGE_SIZE`].
+            #[inline]
+            pub const fn align_down_to_page(addr: usize) -> usize {
+                usize_align_down(addr, PAGE_SIZE)
+            }
+
+            /// Align address upwards to [`PAGE_SIZE`].
+            ///
+            /// Panics if an overflow occurs.
+            #[inline]
+            pub const fn align_up_to_page(addr: usize) -> usize {
+                usize_align_up(addr, PAGE_SIZE)
+            }
+
+            /// Align address downwards to [`LARGE_PAGE_SIZE`].
+            #[inline]
+            pub const fn align_down_to_large_page(addr: usize) -> usize {
+                usize_align_down(addr, LARGE_PAGE_SIZE)
+            }
+
+            /// Align address upwards to [`LARGE_PAGE_SIZE`].
+            ///
+            /// Panics if an overflow occurs.
+            #[inline]
+            pub const fn align_up_to_large_page(addr: usize) -> usize {
+                usize_align_up(addr, LARGE_PAGE_SIZE)
+            }
+
+            /// Align address downwards to [`HUGE_PAGE_SIZE`].
+            #[inline]
+            pub const fn align_down_to_huge_page(addr: usize) -> usize {
+                usize_align_down(addr, HUGE_PAGE_SIZE)
+            }
+
+            /// Align address upwards to [`HUGE_PAGE_SIZE`].
+            ///
+            /// Panics if an overflow occurs.
+            #[inline]
+            pub const fn align_up_to_huge_page(addr: usize) -> usize {
+                usize_align_up(addr, HUGE_PAGE_SIZE)
+            }
+        };
+    }
+
+    /// Page sizes of x86-64 (4 KiB, 2 MiB, 1 GiB).
+    #[cfg(feature = "isa-x86_64")]
+    pub mod x86_64 {
+        isa_impl!(0x1000, 0x20_0000, 0x4000_0000);
+    }
+
+    /// Page sizes of AArch64 with the 4 KiB translation granule (4 KiB, 2 MiB, 1 GiB).
+    ///
+    /// The 16 KiB and 64 KiB granules have different block sizes.
+    #[cfg(feature = "isa-aarch64")]
+    pub mod aarch64 {
+        isa_impl!(0x1000, 0x20_0000, 0x4000_0000);
+    }
+
+    /// Page sizes of RISC-V with Sv39 and larger (4 KiB pages, 2 MiB megapages, 1 GiB gigapages).
+    #[cfg(feature = "isa-riscv")]
+    pub mod riscv {
+        isa_impl!(0x1000, 0x20_0000, 0x4000_0000);
+    }
+}
+
+/// Alignment helpers for cryptographic word sizes.
+///
+/// 32-byte alignment matches 256-bit words, such as SHA-256 and Keccak-256 digests and EVM `uint256` values.
+/// 64-byte alignment matches SHA-512 digests.
+#[cfg(feature = "crypto")]
+pub mod crypto {
+    use crate::{usize_align_down, usize_align_up, usize_is_aligned_to};
+
+    /// The size of 256-bit words in bytes.
+    pub const ALIGN_32: usize = 32;
+
+    /// The size of 512-bit words in bytes.
+    pub const ALIGN_64: usize = 64;
+
+    /// Align address downwards to [`ALIGN_32`].
+    #[inline]
+    pub const fn align_down_32(addr: usize) -> usize {
+        usize_align_down(addr, ALIGN_32)
+    }
+
+    /// Align address upwards to [`ALIGN_32`].
+    ///
+    /// Panics if an overflow occurs.
+    #[inline]
+    pub const fn align_up_32(addr: usize) -> usize {
+        usize_align_up(addr, ALIGN_32)
+    }
+
+    /// Checks whether the address is aligned to [`ALIGN_32`].
+    #[inline]
+    pub const fn is_32_aligned(addr: usize) -> bool {
+        usize_is_aligned_to(addr, ALIGN_32)
+    }
+
+    /// Align address downwards to [`ALIGN_64`].
+    #[inline]
+    pub const fn align_down_64(addr: usize) -> usize {
+        usize_align_down(addr, ALIGN_64)
+    }
+
+    /// Align address upwards to [`ALIGN_64`].
+    ///
+    /// Panics if an overflow occurs.
+    #[inline]
+    pub const fn align_up_64(addr: usize) -> usize {
+        usize_align_up(addr, ALIGN_64)
+    }
+
+    /// Checks whether the address is aligned to [`ALIGN_64`].
+    #[inline]
+    pub const fn is_64_aligned(addr: usize) -> bool {
+        usize_is_aligned_to(addr, ALIGN_64)
+    }
+}
+
+/// Alignment helpers for [SimpleSerialize (SSZ)] encoding.
+///
+/// [SimpleSerialize (SSZ)]: https://github.com/ethereum/consensus-specs/blob/dev/ssz/simple-serialize.md
+#[cfg(feature = "ssz")]
+pub mod ssz {
+    use crate::usize_align_up;
+
+    /// The alignment of basic SSZ types in bytes.
+    pub const SSZ_BASIC_ALIGN: usize = 4;
+
+    /// The alignment of SSZ hashes (chunks) in bytes.
+    pub const SSZ_HASH_ALIGN: usize = 32;
+
+    /// Align address upwards to the next field boundary.
+    ///
+    /// Returns the smallest `x` with alignment [`SSZ_BASIC_ALIGN`] so that `x >= addr`.
+    ///
+    /// Panics if an overflow occurs.
+    #[inline]
+    pub const fn align_up_to_ssz_field(addr: usize) -> usize {
+        usize_align_up(addr, SSZ_BASIC_ALIGN)
+    }
+
+    /// Align address upwards to the next list chunk boundary.
+    ///
+    /// Returns the smallest `x` with alignment [`SSZ_HASH_ALIGN`] so that `x >= addr`.
+    ///
+    /// Panics if an overflow occurs.
+    #[inline]
+    pub const fn align_up_to_ssz_list(addr: usize) -> usize {
+        usize_align_up(addr, SSZ_HASH_ALIGN)
+    }
+}
+
+macro_rules! align_coverage_impl {
+    ($u:ty, $align_coverage_log2:ident) => {
+        /// Computes the base-2 logarithm of the smallest power of two that covers `size`.
+        ///
+        /// Returns the smallest `n` so that `2^n >= size`.
+        /// Sizes of zero and one both return `0`.
+        #[inline]
+        pub const fn $align_coverage_log2(size: $u) -> u32 {
+            if size <= 1 {
+                0
+            } else {
+                <$u>::BITS - (size - 1).leading_zeros()
+            }
+        }
+    };
+}
+
+align_coverage_impl!(u8, u8_align_coverage_log2);
+align_coverage_impl!(u16, u16_align_coverage_log2);
+align_coverage_impl!(u32, u32_align_coverage_log2);
+align_coverage_impl!(u64, u64_align_coverage_log2);
+align_coverage_impl!(u128, u128_align_coverage_log2);
+align_coverage_impl!(usize, usize_align_coverage_log2);
+
+/// Align a constant upwards.
+///
+/// This expands to an inline `const` block with the bit operations and can be used wherever a constant is expected, such as array lengths.
+/// Unlike the functions, this works with any integer type that the expression is inferred to.
+///
+/// Fails to compile if the alignment is not a power of two or if an overflow occurs.
+///
+/// # Examples
+///
+/// ```
+/// use align_address::const_align_up;
+///
+/// let buffer = [0_u8; const_align_up!(1500, 64)];
+/// assert_eq!(buffer.len(), 1536);
+/// ```
+///
+/// ```compile_fail
+/// use align_address::const_align_up;
+///
+/// let buffer = [0_u8; const_align_up!(1500, 48)];
+/// ```
+#[macro_export]
+macro_rules! const_align_up {
+    ($addr:expr, $align:expr $(,)?) => {
+        const {
+            let addr = $addr;
+            let align = $align;
+            ::core::assert!(
+                align != 0 && align & (align - 1) == 0,
+                "`align` must be a power of two"
+            );
+            (addr + align - 1) & !(align - 1)
+        }
+    };
+}
+
+/// Align a constant downwards.
+///
+/// This expands to an inline `const` block with the bit operations and can be used wherever a constant is expected, such as array lengths.
+/// Unlike the functions, this works with any integer type that the expression is inferred to.
+///
+/// Fails to compile if the alignment is not a power of two or if an overflow occurs.
+///
+/// # Examples
+///
+/// ```
+/// use align_address::const_align_down;
+///
+/// let buffer = [0_u8; const_align_down!(1500, 64)];
+/// assert_eq!(buffer.len(), 1472);
+/// ```
+#[macro_export]
+macro_rules! const_align_down {
+    ($addr:expr, $align:expr $(,)?) => {
+        const {
+            let addr = $addr;
+            let align = $align;
+            ::core::assert!(
+                align != 0 && align & (align - 1) == 0,
+                "`align` must be a power of two"
+            );
+            addr & !(align - 1)
+        }
+    };
+}
+
+/// Asserts at compile time that a type has at least the demanded alignment.
+///
+/// The addresses of statics are only known at link time, so this checks the alignment of the type instead.
+///
+/// # Examples
+///
+/// ```
+/// use align_address::static_assert_aligned;
+///
+/// #[repr(align(4096))]
+/// struct Page([u8; 4096]);
+///
+/// static_assert_aligned!(Page, 4096);
+/// static_assert_aligned!(u32, 4);
+/// ```
+///
+/// ```compile_fail
+/// use align_address::static_assert_aligned;
+///
+/// static_assert_aligned!([u8; 4096], 4096);
+/// ```
+#[macro_export]
+macro_rules! static_assert_aligned {
+    ($ty:ty, $align:expr $(,)?) => {
+        const _: () = ::core::assert!(
+            ::core::mem::align_of::<$ty>() >= $align,
+            "type is not sufficiently aligned"
+        );
+    };
+}
+
+macro_rules! align_region_impl {
+    ($u:ty, $is_aligned_to:ident, $align_region_valid:ident) => {
+        /// Checks whether the region is a valid aligned region.
+        ///
+        /// A region is valid if `base` has the demanded alignment, `size` is a multiple of `align`, and `base + size` does not overflow.
+        ///
+        /// Panics if the alignment is not a power of two.
+        #[inline]
+        pub const fn $align_region_valid(base: $u, size: $u, align: $u) -> bool {
+            $is_aligned_to(base, align)
+                && $is_aligned_to(size, align)
+                && base.checked_add(size).is_some()
+        }
+    };
+}
+
+align_region_impl!(u8, u8_is_aligned_to, u8_align_region_valid);
+align_region_impl!(u16, u16_is_aligned_to, u16_align_region_valid);
+align_region_impl!(u32, u32_is_aligned_to, u32_align_region_valid);
+align_region_impl!(u64, u64_is_aligned_to, u64_align_region_valid);
+align_region_impl!(u128, u128_is_aligned_to, u128_align_region_valid);
+align_region_impl!(usize, usize_is_aligned_to, usize_align_region_valid);
+
+macro_rules! align_saturating_cast_impl {
+    ($from:ty, $to:ty, $saturating_align_up:ident, $align_up_saturating_cast:ident) => {
+        /// Align address upwards and narrow it, saturating at the numeric bounds.
+        ///
+        /// Returns the smallest `x` with alignment `align` so that `x >= addr`,
+        #[doc = concat!("or `", stringify!($to), "::MAX` if `x` does not fit into a `", stringify!($to), "`.")]
+        ///
+        /// Panics if the alignment is not a power of two.
+        #[inline]
+        pub const fn $align_up_saturating_cast(addr: $from, align: $to) -> $to {
+            let aligned = $saturating_align_up(addr, align as $from);
+            if aligned > <$to>::MAX as $from {
+                <$to>::MAX
+            } else {
+                aligned as $to
+            }
+        }
+    };
+}
+
+align_saturating_cast_impl!(
+    u16,
+    u8,
+    u16_saturating_align_up,
+    u16_align_up_saturating_cast_u8
+);
+align_saturating_cast_impl!(
+    u32,
+    u8,
+    u32_saturating_align_up,
+    u32_align_up_saturating_cast_u8
+);
+align_saturating_cast_impl!(
+    u32,
+    u16,
+    u32_saturating_align_up,
+    u32_align_up_saturating_cast_u16
+);
+align_saturating_cast_impl!(
+    u64,
+    u8,
+    u64_saturating_align_up,
+    u64_align_up_saturating_cast_u8
+);
+align_saturating_cast_impl!(
+    u64,
+    u16,
+    u64_saturating_align_up,
+    u64_align_up_saturating_cast_u16
+);
+align_saturating_cast_impl!(
+    u64,
+    u32,
+    u64_saturating_align_up,
+    u64_align_up_saturating_cast_u32
+);
+align_saturating_cast_impl!(
+    u64,
+    usize,
+    u64_saturating_align_up,
+    u64_align_up_saturating_cast_usize
+);
+align_saturating_cast_impl!(
+    u128,
+    u8,
+    u128_saturating_align_up,
+    u128_align_up_saturating_cast_u8
+);
+align_saturating_cast_impl!(
+    u128,
+    u16,
+    u128_saturating_align_up,
+    u128_align_up_saturating_cast_u16
+);
+align_saturating_cast_impl!(
+    u128,
+    u32,
+    u128_saturating_align_up,
+    u128_align_up_saturating_cast_u32
+);
+align_saturating_cast_impl!(
+    u128,
+    u64,
+    u128_saturating_align_up,
+    u128_align_up_saturating_cast_u64
+);
+align_saturating_cast_impl!(
+    u128,
+    usize,
+    u128_saturating_align_up,
+    u128_align_up_saturating_cast_usize
+);
+align_saturating_cast_impl!(
+    usize,
+    u8,
+    usize_saturating_align_up,
+    usize_align_up_saturating_cast_u8
+);
+align_saturating_cast_impl!(
+    usize,
+    u16,
+    usize_saturating_align_up,
+    usize_align_up_saturating_cast_u16
+);
+
+macro_rules! align_stride_impl {
+    ($u:ty, $checked_align_up:ident, $align_up_with_stride_and_base:ident) => {
+        /// Align address upwards to a strided sequence starting at `base`.
+        ///
+        /// Returns the smallest `x = base + n * stride` so that `x >= addr`,
+        /// or `None` if an overflow occurs.
+        ///
+        /// Panics if the stride is not a power of two.
+        #[inline]
+        pub const fn $align_up_with_stride_and_base(addr: $u, base: $u, stride: $u) -> Option<$u> {
+            let Some(offset) = addr.checked_sub(base) else {
+                assert_power_of_two!(stride, "`stride` must be a power of two");
+                return Some(base);
+            };
+            let Some(offset) = $checked_align_up(offset, stride) else {
+                return None;
+            };
+            base.checked_add(offset)
+        }
+    };
+}
+
+align_stride_impl!(u8, u8_checked_align_up, u8_align_up_with_stride_and_base);
+align_stride_impl!(u16, u16_checked_align_up, u16_align_up_with_stride_and_base);
+align_stride_impl!(u32, u32_checked_align_up, u32_align_up_with_stride_and_base);
+align_stride_impl!(u64, u64_checked_align_up, u64_align_up_with_stride_and_base);
+align_stride_impl!(
+    u128,
+    u128_checked_align_up,
+    u128_align_up_with_stride_and_base
+);
+align_stride_impl!(
+    usize,
+    usize_checked_align_up,
+    usize_align_up_with_stride_and_base
+);
+
+macro_rules! align_widen_impl {
+    (
+        $u:ty,
+        $a:ty,
+        $align_down:ident,
+        $align_up:ident,
+        $align_down_widen:ident,
+        $align_up_widen:ident
+    ) => {
+        /// Align address downwards to a narrower alignment.
+        ///
+        /// Returns the greatest `x` with alignment `align` so that `x <= addr`.
+        ///
+        /// Panics if the alignment is not a power of two.
+        #[inline]
+        pub const fn $align_down_widen(addr: $u, align: $a) -> $u {
+            $align_down(addr, align as $u)
+        }
+
+        /// Align address upwards to a narrower alignment.
+        ///
+        /// Returns the smallest `x` with alignment `align` so that `x >= addr`.
+        ///
+        /// Panics if the alignment is not a power of two or if an overflow occurs.
+        #[inline]
+        pub const fn $align_up_widen(addr: $u, align: $a) -> $u {
+            $align_up(addr, align as $u)
+        }
+    };
+}
+
+align_widen_impl!(
+    u16,
+    u8,
+    u16_align_down,
+    u16_align_up,
+    u16_align_down_u8,
+    u16_align_up_u8
+);
+align_widen_impl!(
+    u32,
+    u8,
+    u32_align_down,
+    u32_align_up,
+    u32_align_down_u8,
+    u32_align_up_u8
+);
+align_widen_impl!(
+    u32,
+    u16,
+    u32_align_down,
+    u32_align_up,
+    u32_align_down_u16,
+    u32_align_up_u16
+);
+align_widen_impl!(
+    u64,
+    u8,
+    u64_align_down,
+    u64_align_up,
+    u64_align_down_u8,
+    u64_align_up_u8
+);
+align_widen_impl!(
+    u64,
+    u16,
+    u64_align_down,
+    u64_align_up,
+    u64_align_down_u16,
+    u64_align_up_u16
+);
+align_widen_impl!(
+    u64,
+    u32,
+    u64_align_down,
+    u64_align_up,
+    u64_align_down_u32,
+    u64_align_up_u32
+);
+align_widen_impl!(
+    u128,
+    u8,
+    u128_align_down,
+    u128_align_up,
+    u128_align_down_u8,
+    u128_align_up_u8
+);
+align_widen_impl!(
+    u128,
+    u16,
+    u128_align_down,
+    u128_align_up,
+    u128_align_down_u16,
+    u128_align_up_u16
+);
+align_widen_impl!(
+    u128,
+    u32,
+    u128_align_down,
+    u128_align_up,
+    u128_align_down_u32,
+    u128_align_up_u32
+);
+align_widen_impl!(
+    u128,
+    u64,
+    u128_align_down,
+    u128_align_up,
+    u128_align_down_u64,
+    u128_align_up_u64
+);
+align_widen_impl!(
+    usize,
+    u8,
+    usize_align_down,
+    usize_align_up,
+    usize_align_down_u8,
+    usize_align_up_u8
+);
+align_widen_impl!(
+    usize,
+    u16,
+    usize_align_down,
+    usize_align_up,
+    usize_align_down_u16,
+    usize_align_up_u16
+);
+
+/// Alignment of bit counts, such as positions in bit streams.
+///
+/// Bit counts and bit alignments share the same unit, so these functions behave like their byte counterparts.
+///
+/// # Examples
+///
+/// ```
+/// use align_address::bits::align_up_bits;
+///
+/// assert_eq!(align_up_bits(13, 8), 16);
+/// ```
+pub mod bits {
+    use crate::{u64_align_down, u64_align_up, u64_is_aligned_to};
+
+    /// Align a bit count downwards.
+    ///
+    /// Returns the greatest `x` with alignment `align_bits` so that `x <= bit_count`.
+    ///
+    /// Panics if the alignment is not a power of two.
+    #[inline]
+    pub const fn align_down_bits(bit_count: u64, align_bits: u64) -> u64 {
+        u64_align_down(bit_count, align_bits)
+    }
+
+    /// Align a bit count upwards.
+    ///
+    /// Returns the smallest `x` with alignment `align_bits` so that `x >= bit_count`.
+    ///
+    /// Panics if the alignment is not a power of two or if an overflow occurs.
+    #[inline]
+    pub const fn align_up_bits(bit_count: u64, align_bits: u64) -> u64 {
+        u64_align_up(bit_count, align_bits)
+    }
+
+    /// Checks whether the bit count has the demanded alignment.
+    #[inline]
+    pub const fn is_aligned_bits(bit_count: u64, align_bits: u64) -> bool {
+        u64_is_aligned_to(bit_count, align_bits)
+    }
+}
+
+/// Checks whether the address is suitably aligned to hold a `U`.
+///
+/// This is [`usize_is_aligned_to`] with [`mem::align_of::<U>()`](mem::align_of).
+#[inline(always)]
+pub const fn is_aligned_to_type_align<U>(addr: usize) -> bool {
+    usize_is_aligned_to(addr, mem::align_of::<U>())
+}
+
+/// Checks whether the pointer is suitably aligned to hold a `U`.
+///
+/// This is [`is_aligned_to_type_align`] for the address of `ptr`.
+#[inline(always)]
+pub fn is_ptr_aligned_for<U>(ptr: *const u8) -> bool {
+    is_aligned_to_type_align::<U>(ptr as usize)
+}
+
+/// Align a pointer downwards and cast it to `*mut T`.
+///
+/// Returns `ptr` moved to the greatest address `x` with alignment `align` so that `x <= ptr`.
+/// The returned pointer keeps the provenance of `ptr`, but may not point to a valid `T`.
+///
+/// Panics if the alignment is not a power of two or if it is smaller than [`mem::align_of::<T>()`](mem::align_of).
+#[inline]
+pub fn align_down_pointer_to<T>(ptr: *mut u8, align: usize) -> *mut T {
+    assert!(
+        align >= mem::align_of::<T>(),
+        "`align` must not be smaller than the alignment of `T`"
+    );
+    let addr = ptr as usize;
+    ptr.wrapping_sub(addr - usize_align_down(addr, align))
+        .cast()
+}
+
+/// Align a pointer upwards and cast it to `*mut T`.
+///
+/// Returns `ptr` moved to the smallest address `x` with alignment `align` so that `x >= ptr`.
+/// The returned pointer keeps the provenance of `ptr`, but may not point to a valid `T`.
+///
+/// Panics if the alignment is not a power of two, if it is smaller than [`mem::align_of::<T>()`](mem::align_of), or if an overflow occurs.
+#[inline]
+pub fn align_up_pointer_to<T>(ptr: *mut u8, align: usize) -> *mut T {
+    assert!(
+        align >= mem::align_of::<T>(),
+        "`align` must not be smaller than the alignment of `T`"
+    );
+    let addr = ptr as usize;
+    ptr.wrapping_add(usize_align_up(addr, align) - addr).cast()
+}
+
+/// Alignment masks `(1 << i) - 1`, indexed by the base-2 logarithm `i` of the alignment.
+static ALIGN_MASKS: [usize; usize::BITS as usize] = {
+    let mut masks = [0; usize::BITS as usize];
+    let mut i = 0;
+    while i < masks.len() {
+        masks[i] = (1 << i) - 1;
+        i += 1;
+    }
+    masks
+};
+
+/// Align address upwards to `1 << log2_align` using a precomputed mask table.
+///
+/// Returns the smallest `x` with alignment `1 << log2_align` so that `x >= addr`.
+/// This looks up the mask instead of computing it, for example for page-table hot paths.
+///
+/// Panics if `log2_align >= usize::BITS` or if an overflow occurs.
+#[inline]
+pub fn align_up_log2_table(addr: usize, log2_align: usize) -> usize {
+    let mask = ALIGN_MASKS[log2_align];
+    let Some(addr) = addr.checked_add(mask) else {
+        panic!("attempt to add with overflow")
+    };
+    addr & !mask
+}
+
+/// Checks whether a segment's virtual address and file offset are congruent modulo the page size.
+///
+/// The ELF System V ABI requires this of loadable segments, since they are mapped page by page: "loadable process segments must have congruent values for `p_vaddr` and `p_offset`, modulo the page size" (gABI, Program Header, `p_align`).
+///
+/// Panics if the page size is not a power of two.
+#[inline]
+pub const fn segments_compatible_alignment(vaddr: usize, offset: usize, page_size: usize) -> bool {
+    usize_is_aligned_to(vaddr.wrapping_sub(offset), page_size)
+}
+
+/// Adjust a segment's virtual address to be congruent to its file offset modulo the page size.
+///
+/// Returns the smallest `x` so that `x >= vaddr` and [`segments_compatible_alignment`]`(x, offset, page_size)`.
+///
+/// Panics if the page size is not a power of two or if an overflow occurs.
+#[inline]
+pub const fn align_segment_vaddr(vaddr: usize, offset: usize, page_size: usize) -> usize {
+    assert_power_of_two!(page_size, "`page_size` must be a power of two");
+    let delta = offset.wrapping_sub(vaddr) & (page_size - 1);
+    let Some(vaddr) = vaddr.checked_add(delta) else {
+        panic!("attempt to add with overflow")
+    };
+    vaddr
+}
+
+macro_rules! align_units_impl {
+    ($u:ty, $align_down_in_units:ident, $align_up_in_units:ident) => {
+        /// Counts the whole units that fit into `size`.
+        ///
+        /// Returns `align_down(size, unit) / unit`.
+        ///
+        /// Panics if the unit is not a power of two.
+        #[inline]
+        pub const fn $align_down_in_units(size: $u, unit: $u) -> $u {
+            assert_power_of_two!(unit, "`unit` must be a power of two");
+            size >> unit.trailing_zeros()
+        }
+
+        /// Counts the units needed to cover `size`.
+        ///
+        /// Returns `align_up(size, unit) / unit`.
+        /// Unlike aligning upwards, this never overflows.
+        ///
+        /// Panics if the unit is not a power of two.
+        #[inline]
+        pub const fn $align_up_in_units(size: $u, unit: $u) -> $u {
+            let units = $align_down_in_units(size, unit);
+            if size & (unit - 1) == 0 {
+                units
+            } else {
+                units + 1
+            }
+        }
+    };
+}
+
+align_units_impl!(u8, u8_align_down_in_units, u8_align_up_in_units);
+align_units_impl!(u16, u16_align_down_in_units, u16_align_up_in_units);
+align_units_impl!(u32, u32_align_down_in_units, u32_align_up_in_units);
+align_units_impl!(u64, u64_align_down_in_units, u64_align_up_in_units);
+align_units_impl!(u128, u128_align_down_in_units, u128_align_up_in_units);
+align_units_impl!(usize, usize_align_down_in_units, usize_align_up_in_units);
+
+macro_rules! align_debug_impl {
+    ($u:ty, $align_up:ident, $saturating_align_up:ident, $align_up_no_overflow_debug:ident) => {
+        /// Align address upwards, panicking on overflow only if debug assertions are enabled.
+        ///
+        /// Returns the smallest `x` with alignment `align` so that `x >= addr`.
+        ///
+        #[doc = concat!("If debug assertions are enabled, this behaves like [`", stringify!($align_up), "`] and panics if an overflow occurs.")]
+        #[doc = concat!("Otherwise, this behaves like [`", stringify!($saturating_align_up), "`] and saturates at the numeric bounds.")]
+        ///
+        /// Panics if the alignment is not a power of two.
+        #[inline]
+        pub const fn $align_up_no_overflow_debug(addr: $u, align: $u) -> $u {
+            #[cfg(debug_assertions)]
+            {
+                $align_up(addr, align)
+            }
+            #[cfg(not(debug_assertions))]
+            {
+                $saturating_align_up(addr, align)
+            }
+        }
+    };
+}
+
+align_debug_impl!(
+    u8,
+    u8_align_up,
+    u8_saturating_align_up,
+    u8_align_up_no_overflow_debug
+);
+align_debug_impl!(
+    u16,
+    u16_align_up,
+    u16_saturating_align_up,
+    u16_align_up_no_overflow_debug
+);
+align_debug_impl!(
+    u32,
+    u32_align_up,
+    u32_saturating_align_up,
+    u32_align_up_no_overflow_debug
+);
+align_debug_impl!(
+    u64,
+    u64_align_up,
+    u64_saturating_align_up,
+    u64_align_up_no_overflow_debug
+);
+align_debug_impl!(
+    u128,
+    u128_align_up,
+    u128_saturating_align_up,
+    u128_align_up_no_overflow_debug
+);
+align_debug_impl!(
+    usize,
+    usize_align_up,
+    usize_saturating_align_up,
+    usize_align_up_no_overflow_debug
+);
+
+macro_rules! align_region_subtract_impl {
+    ($u:ty, $align_region_subtract:ident) => {
+        /// Removes a sub-region from a region.
+        ///
+        /// Returns the remainders `[(left_base, left_size), (right_base, right_size)]` of the region `(base, size)` after removing `(sub_base, sub_size)`.
+        /// A remainder is empty (has size 0) if the sub-region touches the respective end of the region.
+        /// The parts of the sub-region that lie outside the region are ignored.
+        /// If the region and sub-region are aligned, so are the remainders.
+        ///
+        /// Panics if `base + size` overflows.
+        #[inline]
+        pub const fn $align_region_subtract(
+            base: $u,
+            size: $u,
+            sub_base: $u,
+            sub_size: $u,
+        ) -> [($u, $u); 2] {
+            let Some(end) = base.checked_add(size) else {
+                panic!("attempt to add with overflow")
+            };
+            let sub_end = sub_base.saturating_add(sub_size);
+            let left_end = if sub_base < base {
+                base
+            } else if sub_base > end {
+                end
+            } else {
+                sub_base
+            };
+            let right_base = if sub_end < left_end {
+                left_end
+            } else if sub_end > end {
+                end
+            } else {
+                sub_end
+            };
+            [(base, left_end - base), (right_base, end - right_base)]
+        }
+    };
+}
+
+align_region_subtract_impl!(u8, u8_align_region_subtract);
+align_region_subtract_impl!(u16, u16_align_region_subtract);
+align_region_subtract_impl!(u32, u32_align_region_subtract);
+align_region_subtract_impl!(u64, u64_align_region_subtract);
+align_region_subtract_impl!(u128, u128_align_region_subtract);
+align_region_subtract_impl!(usize, usize_align_region_subtract);
+
+macro_rules! align_overflow_impl {
+    (
+        $u:ty,
+        $checked_align_up:ident,
+        $wrapping_align_up:ident,
+        $align_up_or_overflow:ident
+    ) => {
+        /// Align address upwards, reporting overflows.
+        ///
+        /// Returns the smallest `x` with alignment `align` so that `x >= addr`,
+        #[doc = concat!("or the wrapped result of [`", stringify!($wrapping_align_up), "`] as `Err` if an overflow occurs.")]
+        ///
+        /// Panics if the alignment is not a power of two.
+        #[inline]
+        pub const fn $align_up_or_overflow(addr: $u, align: $u) -> Result<$u, $u> {
+            if let Some(aligned) = $checked_align_up(addr, align) {
+                Ok(aligned)
+            } else {
+                Err($wrapping_align_up(addr, align))
+            }
+        }
+    };
+}
+
+align_overflow_impl!(
+    u8,
+    u8_checked_align_up,
+    u8_wrapping_align_up,
+    u8_align_up_or_overflow
+);
+align_overflow_impl!(
+    u16,
+    u16_checked_align_up,
+    u16_wrapping_align_up,
+    u16_align_up_or_overflow
+);
+align_overflow_impl!(
+    u32,
+    u32_checked_align_up,
+    u32_wrapping_align_up,
+    u32_align_up_or_overflow
+);
+align_overflow_impl!(
+    u64,
+    u64_checked_align_up,
+    u64_wrapping_align_up,
+    u64_align_up_or_overflow
+);
+align_overflow_impl!(
+    u128,
+    u128_checked_align_up,
+    u128_wrapping_align_up,
+    u128_align_up_or_overflow
+);
+align_overflow_impl!(
+    usize,
+    usize_checked_align_up,
+    usize_wrapping_align_up,
+    usize_align_up_or_overflow
+);
+
+/// Alignment helpers for cache coloring.
+///
+/// A cache with `sets` sets of `line_size` bytes each repeats its set mapping every `sets * line_size` bytes (the way size).
+pub mod cache {
+    use core::alloc::Layout;
+
+    use crate::usize_align_up;
+
+    /// The cache-line size assumed by [`align_to_cache_aligned_struct`].
+    ///
+    /// This is 64 bytes, which is common on current x86-64 and AArch64 processors.
+    pub const CACHE_LINE_SIZE: usize = 64;
+
+    /// Align address upwards to the next way boundary.
+    ///
+    /// Returns `align_up(addr, sets * line_size)`, the next address that maps to the first cache set.
+    ///
+    /// Panics if `sets * line_size` is not a power of two or if an overflow occurs.
+    #[inline]
+    pub const fn align_up_to_next_cache_set(addr: usize, sets: usize, line_size: usize) -> usize {
+        let Some(way_size) = sets.checked_mul(line_size) else {
+            panic!("attempt to multiply with overflow")
+        };
+        usize_align_up(addr, way_size)
+    }
+
+    /// Computes the cache set that the address maps to.
+    ///
+    /// Returns `(addr / line_size) % sets`.
+    ///
+    /// Panics if `sets` or `line_size` is zero.
+    #[inline]
+    pub const fn cache_set_index(addr: usize, sets: usize, line_size: usize) -> usize {
+        (addr / line_size) % sets
+    }
+
+    /// Computes the layout of a cache-line-aligned array of `count` instances of `T`.
+    ///
+    /// Returns [`Layout::array::<T>(count)`](Layout::array) aligned to at least [`CACHE_LINE_SIZE`] and padded to a multiple of its alignment, so that the allocation neither starts nor ends in a shared cache line.
+    ///
+    /// Panics if an overflow occurs.
+    #[inline]
+    pub fn align_to_cache_aligned_struct<T>(count: usize) -> Layout {
+        Layout::array::<T>(count)
+            .and_then(|layout| layout.align_to(CACHE_LINE_SIZE))
+            .expect("attempt to compute layout with overflow")
+            .pad_to_align()
+    }
+}
+
+/// Align the start address of a slice downwards.
+///
+/// Returns the greatest `x` with alignment `align` so that `x <= s.as_ptr()`.
+/// This is the base of the aligned region covering `s`, for example for `mprotect` or `mlock`.
+///
+/// Panics if the alignment is not a power of two.
+#[inline]
+pub fn align_down_slice_addr<T>(s: &[T], align: usize) -> usize {
+    usize_align_down(s.as_ptr() as usize, align)
+}
+
+/// Align the end address of a slice upwards.
+///
+/// Returns the smallest `x` with alignment `align` so that `x >= s.as_ptr() + size_of_val(s)`.
+/// This is the end of the aligned region covering `s`, for example for `mprotect` or `mlock`.
+///
+/// Panics if the alignment is not a power of two or if an overflow occurs.
+#[inline]
+pub fn align_up_slice_end<T>(s: &[T], align: usize) -> usize {
+    usize_align_up(s.as_ptr() as usize + mem::size_of_val(s), align)
+}
+
+/// Merge adjacent or overlapping `(base, end)` regions.
+///
+/// `regions` must be sorted by base.
+/// Regions are merged if the end of one is at or beyond the base of the next, for example in memory maps.
+#[cfg(feature = "alloc")]
+#[inline]
+pub fn merge_adjacent_aligned_regions<T: Ord + Copy>(regions: &mut Vec<(T, T)>) {
+    regions.dedup_by(|next, prev| {
+        if next.0 <= prev.1 {
+            prev.1 = prev.1.max(next.1);
+            true
+        } else {
+            false
+        }
+    });
+}
+
+/// Merge adjacent or overlapping `(base, end)` regions in place.
+///
+/// `regions` must be sorted by base.
+/// Regions are merged if the end of one is at or beyond the base of the next, for example in memory maps.
+///
+/// Returns the number of merged regions, which are moved to the front of `regions`.
+/// The remaining regions are left in an unspecified state.
+#[inline]
+pub fn merge_adjacent_aligned_regions_fixed<T: Ord + Copy, const N: usize>(
+    regions: &mut [(T, T); N],
+) -> usize {
+    let mut len = 0;
+    for i in 0..N {
+        let (base, end) = regions[i];
+        if len > 0 && base <= regions[len - 1].1 {
+            regions[len - 1].1 = regions[len - 1].1.max(end);
+        } else {
+            regions[len] = (base, end);
+            len += 1;
+        }
+    }
+    len
+}
+
+/// Checks that an [`Align`] implementation upholds its invariants for the given inputs.
+///
+/// This asserts that:
+/// * `addr.align_down(align) <= addr`
+/// * `addr.align_up(align) >= addr`
+/// * both results have alignment `align`
+/// * `addr.align_up(align) - addr.align_down(align)` is either `0` or `align`
+///
+/// These invariants assume that aligning upwards does not overflow.
+/// The implementations for [`Wrapping`] and [`Saturating`] deliberately violate them on overflow, so this check panics for such inputs.
+///
+/// This is only available if debug assertions are enabled.
+///
+/// Panics if any of the invariants is violated or if `align_down` or `align_up` panic.
+#[cfg(debug_assertions)]
+pub fn align_check_invariants<T>(addr: T, align: T)
+where
+    T: Align + PartialOrd + core::ops::Sub<Output = T> + core::fmt::Debug,
+{
+    let down = addr.align_down(align);
+    let up = addr.align_up(align);
+    assert!(
+        down <= addr,
+        "{down:?} = align_down({addr:?}, {align:?}) > {addr:?}"
+    );
+    assert!(
+        up >= addr,
+        "{up:?} = align_up({addr:?}, {align:?}) < {addr:?}"
+    );
+    assert!(
+        down.is_aligned_to(align),
+        "{down:?} = align_down({addr:?}, {align:?}) is not aligned"
+    );
+    assert!(
+        up.is_aligned_to(align),
+        "{up:?} = align_up({addr:?}, {align:?}) is not aligned"
+    );
+    assert!(
+        up == down || up - down == align,
+        "align_up({addr:?}, {align:?}) - align_down({addr:?}, {align:?}) is neither 0 nor {align:?}"
+    );
+}
+
+/// Align address upwards and check the result against a reference implementation.
+///
+/// Returns [`Align::align_up`]`(addr, align)`.
+/// If debug assertions are enabled, this also asserts that `reference(addr, align)` returns the same, for example to test a hand-written fast path.
+///
+/// Panics if the alignment is not a power of two or if an overflow occurs.
+#[inline]
+pub fn align_up_verify<T>(addr: T, align: T, reference: fn(T, T) -> T) -> T
+where
+    T: Align + core::fmt::Debug,
+{
+    let aligned = addr.align_up(align);
+    debug_assert_eq!(
+        aligned,
+        reference(addr, align),
+        "reference implementation differs for align_up({addr:?}, {align:?})"
+    );
+    aligned
+}
+
+macro_rules! align_sub_impl {
+    ($u:ty, $align_down:ident, $align_saturating_sub:ident, $align_checked_sub:ident) => {
+        /// Computes the base of the previous aligned block, saturating at zero.
+        ///
+        /// Returns `align_down(addr, align) - align`, or `0` if an underflow occurs.
+        ///
+        /// Panics if the alignment is not a power of two.
+        #[inline]
+        pub const fn $align_saturating_sub(addr: $u, align: $u) -> $u {
+            $align_down(addr, align).saturating_sub(align)
+        }
+
+        /// Computes the base of the previous aligned block.
+        ///
+        /// Returns `align_down(addr, align) - align`, or `None` if an underflow occurs.
+        ///
+        /// Panics if the alignment is not a power of two.
+        #[inline]
+        pub const fn $align_checked_sub(addr: $u, align: $u) -> Option<$u> {
+            $align_down(addr, align).checked_sub(align)
+        }
+    };
+}
+
+align_sub_impl!(
+    u8,
+    u8_align_down,
+    u8_align_saturating_sub,
+    u8_align_checked_sub
+);
+align_sub_impl!(
+    u16,
+    u16_align_down,
+    u16_align_saturating_sub,
+    u16_align_checked_sub
+);
+align_sub_impl!(
+    u32,
+    u32_align_down,
+    u32_align_saturating_sub,
+    u32_align_checked_sub
+);
+align_sub_impl!(
+    u64,
+    u64_align_down,
+    u64_align_saturating_sub,
+    u64_align_checked_sub
+);
+align_sub_impl!(
+    u128,
+    u128_align_down,
+    u128_align_saturating_sub,
+    u128_align_checked_sub
+);
+align_sub_impl!(
+    usize,
+    usize_align_down,
+    usize_align_saturating_sub,
+    usize_align_checked_sub
+);
+
+macro_rules! align_pad_impl {
+    ($u:ty, $offset_to_aligned:ident, $align_pad_to:ident, $align_pad_bytes:ident) => {
+        /// Computes the number of padding bytes needed to align the address upwards.
+        ///
+        #[doc = concat!("This is [`", stringify!($offset_to_aligned), "`] as a `usize`.")]
+        ///
+        /// Panics if the alignment is not a power of two or if the padding does not fit into a `usize`.
+        #[inline]
+        pub const fn $align_pad_to(addr: $u, align: $u) -> usize {
+            let pad = $offset_to_aligned(addr, align);
+            assert!(
+                pad as u128 <= usize::MAX as u128,
+                "padding does not fit into `usize`"
+            );
+            pad as usize
+        }
+
+        /// Creates the zero padding bytes needed to align the address upwards.
+        ///
+        #[doc = concat!("Returns a vector of [`", stringify!($align_pad_to), "`] zero bytes.")]
+        ///
+        /// Panics if the alignment is not a power of two or if the padding does not fit into a `usize`.
+        #[cfg(feature = "alloc")]
+        #[inline]
+        pub fn $align_pad_bytes(addr: $u, align: $u) -> Vec<u8> {
+            alloc::vec![0; $align_pad_to(addr, align)]
+        }
+    };
+}
+
+align_pad_impl!(
+    u8,
+    u8_offset_to_aligned,
+    u8_align_pad_to,
+    u8_align_pad_bytes
+);
+align_pad_impl!(
+    u16,
+    u16_offset_to_aligned,
+    u16_align_pad_to,
+    u16_align_pad_bytes
+);
+align_pad_impl!(
+    u32,
+    u32_offset_to_aligned,
+    u32_align_pad_to,
+    u32_align_pad_bytes
+);
+align_pad_impl!(
+    u64,
+    u64_offset_to_aligned,
+    u64_align_pad_to,
+    u64_align_pad_bytes
+);
+align_pad_impl!(
+    u128,
+    u128_offset_to_aligned,
+    u128_align_pad_to,
+    u128_align_pad_bytes
+);
+align_pad_impl!(
+    usize,
+    usize_offset_to_aligned,
+    usize_align_pad_to,
+    usize_align_pad_bytes
+);
+
+macro_rules! align_regions_impl {
+    (
+        $u:ty,
+        $align_down:ident,
+        $checked_align_up:ident,
+        $align_regions_up:ident,
+        $align_regions_down:ident
+    ) => {
+        /// Align the bases of `(base, size)` regions upwards, keeping their ends fixed.
+        ///
+        /// Each region shrinks from the front to start at the smallest `x` with alignment `align` so that `x >= base`.
+        /// If the aligned base lies beyond the end of the region, the region becomes empty (has size 0).
+        ///
+        /// Returns the index of the first region for which an overflow occurs as `Err`.
+        ///
+        /// Panics if the alignment is not a power of two.
+        #[inline]
+        pub const fn $align_regions_up<const N: usize>(
+            mut regions: [($u, $u); N],
+            align: $u,
+        ) -> Result<[($u, $u); N], usize> {
+            let mut i = 0;
+            while i < N {
+                let (base, size) = regions[i];
+                let Some(end) = base.checked_add(size) else {
+                    return Err(i);
+                };
+                let Some(base) = $checked_align_up(base, align) else {
+                    return Err(i);
+                };
+                regions[i] = (base, end.saturating_sub(base));
+                i += 1;
+            }
+            Ok(regions)
+        }
+
+        /// Align the ends of `(base, size)` regions downwards, keeping their bases fixed.
+        ///
+        /// Each region shrinks from the back to end at the greatest `x` with alignment `align` so that `x <= base + size`.
+        /// If the aligned end lies before the base of the region, the region becomes empty (has size 0).
+        ///
+        /// Returns the index of the first region for which an overflow occurs as `Err`.
+        ///
+        /// Panics if the alignment is not a power of two.
+        #[inline]
+        pub const fn $align_regions_down<const N: usize>(
+            mut regions: [($u, $u); N],
+            align: $u,
+        ) -> Result<[($u, $u); N], usize> {
+            let mut i = 0;
+            while i < N {
+                let (base, size) = regions[i];
+                let Some(end) = base.checked_add(size) else {
+                    return Err(i);
+                };
+                let end = $align_down(end, align);
+                regions[i] = (base, end.saturating_sub(base));
+                i += 1;
+            }
+            Ok(regions)
+        }
+    };
+}
+
+align_regions_impl!(
+    u8,
+    u8_align_down,
+    u8_checked_align_up,
+    u8_align_regions_up,
+    u8_align_regions_down
+);
+align_regions_impl!(
+    u16,
+    u16_align_down,
+    u16_checked_align_up,
+    u16_align_regions_up,
+    u16_align_regions_down
+);
+align_regions_impl!(
+    u32,
+    u32_align_down,
+    u32_checked_align_up,
+    u32_align_regions_up,
+    u32_align_regions_down
+);
+align_regions_impl!(
+    u64,
+    u64_align_down,
+    u64_checked_align_up,
+    u64_align_regions_up,
+    u64_align_regions_down
+);
+align_regions_impl!(
+    u128,
+    u128_align_down,
+    u128_checked_align_up,
+    u128_align_regions_up,
+    u128_align_regions_down
+);
+align_regions_impl!(
+    usize,
+    usize_align_down,
+    usize_checked_align_up,
+    usize_align_regions_up,
+    usize_align_regions_down
+);
+
+macro_rules! align_sequence_impl {
+    ($u:ty, $align_sequence_start:ident, $align_sequence_end:ident) => {
+        /// Computes the start of the aligned block with the given sequence number.
+        ///
+        /// Returns `seq * block_size`, or `None` if an overflow occurs.
+        ///
+        /// Panics if the block size is not a power of two.
+        #[inline]
+        pub const fn $align_sequence_start(seq: $u, block_size: $u) -> Option<$u> {
+            assert_power_of_two!(block_size, "`block_size` must be a power of two");
+            seq.checked_mul(block_size)
+        }
+
+        /// Computes the end of the aligned block with the given sequence number.
+        ///
+        /// Returns `(seq + 1) * block_size`, or `None` if an overflow occurs.
+        ///
+        /// Panics if the block size is not a power of two.
+        #[inline]
+        pub const fn $align_sequence_end(seq: $u, block_size: $u) -> Option<$u> {
+            let Some(start) = $align_sequence_start(seq, block_size) else {
+                return None;
+            };
+            start.checked_add(block_size)
+        }
+    };
+}
+
+align_sequence_impl!(u8, u8_align_sequence_start, u8_align_sequence_end);
+align_sequence_impl!(u16, u16_align_sequence_start, u16_align_sequence_end);
+align_sequence_impl!(u32, u32_align_sequence_start, u32_align_sequence_end);
+align_sequence_impl!(u64, u64_align_sequence_start, u64_align_sequence_end);
+align_sequence_impl!(u128, u128_align_sequence_start, u128_align_sequence_end);
+align_sequence_impl!(usize, usize_align_sequence_start, usize_align_sequence_end);
+
+macro_rules! align_nonzero_impl {
+    ($u:ty, $checked_align_up:ident, $align_up_nonzero_result:ident) => {
+        /// Align address upwards to a nonzero result.
+        ///
+        /// Returns the smallest `x` with alignment `align` so that `x >= addr`,
+        /// or `None` if `x` is zero or an overflow occurs.
+        ///
+        /// Panics if the alignment is not a power of two.
+        #[inline]
+        pub const fn $align_up_nonzero_result(addr: $u, align: $u) -> Option<$u> {
+            match $checked_align_up(addr, align) {
+                Some(0) | None => None,
+                aligned => aligned,
+            }
+        }
+    };
+}
+
+align_nonzero_impl!(u8, u8_checked_align_up, u8_align_up_nonzero_result);
+align_nonzero_impl!(u16, u16_checked_align_up, u16_align_up_nonzero_result);
+align_nonzero_impl!(u32, u32_checked_align_up, u32_align_up_nonzero_result);
+align_nonzero_impl!(u64, u64_checked_align_up, u64_align_up_nonzero_result);
+align_nonzero_impl!(u128, u128_checked_align_up, u128_align_up_nonzero_result);
+align_nonzero_impl!(usize, usize_checked_align_up, usize_align_up_nonzero_result);
+
+macro_rules! align_min_one_impl {
+    ($u:ty, $align_down:ident, $align_down_to_min_one:ident) => {
+        /// Align address downwards, but not to zero for nonzero addresses.
+        ///
+        /// Returns the greatest `x` with alignment `align` so that `x <= addr`,
+        /// or `1` if `x` is zero but `addr` is not.
+        ///
+        /// Panics if the alignment is not a power of two.
+        #[inline]
+        pub const fn $align_down_to_min_one(addr: $u, align: $u) -> $u {
+            let aligned = $align_down(addr, align);
+            if aligned == 0 && addr != 0 {
+                1
+            } else {
+                aligned
+            }
+        }
+    };
+}
+
+align_min_one_impl!(u8, u8_align_down, u8_align_down_to_min_one);
+align_min_one_impl!(u16, u16_align_down, u16_align_down_to_min_one);
+align_min_one_impl!(u32, u32_align_down, u32_align_down_to_min_one);
+align_min_one_impl!(u64, u64_align_down, u64_align_down_to_min_one);
+align_min_one_impl!(u128, u128_align_down, u128_align_down_to_min_one);
+align_min_one_impl!(usize, usize_align_down, usize_align_down_to_min_one);
+
+macro_rules! align_difference_impl {
+    ($u:ty, $checked_align_up:ident, $align_up_difference:ident) => {
+        /// Returns how far aligning `b` upwards lies beyond aligning `a` upwards.
+        ///
+        /// This is `align_up(b, align) - align_up(a, align)`, e.g. the additional
+        /// space needed to grow a region from `a` to `b` bytes.
+        /// Returns `None` if `b < a` or if aligning either value upwards overflows.
+        ///
+        /// Panics if the alignment is not a power of two.
+        #[inline]
+        pub const fn $align_up_difference(a: $u, b: $u, align: $u) -> Option<$u> {
+            if b < a {
+                return None;
+            }
+            let Some(a) = $checked_align_up(a, align) else {
+                return None;
+            };
+            let Some(b) = $checked_align_up(b, align) else {
+                return None;
+            };
+            Some(b - a)
+        }
+    };
+}
+
+align_difference_impl!(u8, u8_checked_align_up, u8_align_up_difference);
+align_difference_impl!(u16, u16_checked_align_up, u16_align_up_difference);
+align_difference_impl!(u32, u32_checked_align_up, u32_align_up_difference);
+align_difference_impl!(u64, u64_checked_align_up, u64_align_up_difference);
+align_difference_impl!(u128, u128_checked_align_up, u128_align_up_difference);
+align_difference_impl!(usize, usize_checked_align_up, usize_align_up_difference);
+
+macro_rules! align_dma_impl {
+    ($u:ty, $align_up:ident, $align_up_for_dma:ident) => {
+        /// Align address upwards for both a DMA controller and the cache.
+        ///
+        /// Returns the smallest `x` with both alignment `dma_align` and alignment `cache_line_size` so that `x >= addr`.
+        /// Since both are powers of two, this aligns to the larger of the two.
+        ///
+        /// Panics if either alignment is not a power of two or if an overflow occurs.
+        #[inline]
+        pub const fn $align_up_for_dma(addr: $u, dma_align: $u, cache_line_size: $u) -> $u {
+            assert_power_of_two!(dma_align, "`dma_align` must be a power of two");
+            assert_power_of_two!(cache_line_size, "`cache_line_size` must be a power of two");
+            let align = if dma_align > cache_line_size {
+                dma_align
+            } else {
+                cache_line_size
+            };
+            $align_up(addr, align)
+        }
+    };
+}
+
+align_dma_impl!(u8, u8_align_up, u8_align_up_for_dma);
+align_dma_impl!(u16, u16_align_up, u16_align_up_for_dma);
+align_dma_impl!(u32, u32_align_up, u32_align_up_for_dma);
+align_dma_impl!(u64, u64_align_up, u64_align_up_for_dma);
+align_dma_impl!(u128, u128_align_up, u128_align_up_for_dma);
+align_dma_impl!(usize, usize_align_up, usize_align_up_for_dma);
+
+macro_rules! align_block_impl {
+    ($u:ty, $align_down:ident, $align_down_to_containing_block:ident) => {
+        /// Returns the aligned block of size `block_size` that contains `addr`.
+        ///
+        /// The block is returned as `(block_start, block_end)`, where `block_start` is the greatest `x` with alignment `block_size` so that `x <= addr` and `block_end` is `block_start + block_size`, for example in buddy allocators.
+        /// Returns `None` if `block_end` overflows.
+        ///
+        /// Panics if the block size is not a power of two.
+        #[inline]
+        pub const fn $align_down_to_containing_block(addr: $u, block_size: $u) -> Option<($u, $u)> {
+            let block_start = $align_down(addr, block_size);
+            let Some(block_end) = block_start.checked_add(block_size) else {
+                return None;
+            };
+            Some((block_start, block_end))
+        }
+    };
+}
+
+align_block_impl!(u8, u8_align_down, u8_align_down_to_containing_block);
+align_block_impl!(u16, u16_align_down, u16_align_down_to_containing_block);
+align_block_impl!(u32, u32_align_down, u32_align_down_to_containing_block);
+align_block_impl!(u64, u64_align_down, u64_align_down_to_containing_block);
+align_block_impl!(u128, u128_align_down, u128_align_down_to_containing_block);
+align_block_impl!(
+    usize,
+    usize_align_down,
+    usize_align_down_to_containing_block
+);
+
+macro_rules! align_region_complement_impl {
+    ($u:ty, $align_region_complement:ident) => {
+        /// Removes a sub-region from a region given as `(start, end)` pairs.
+        ///
+        /// Returns the remainders `[(universe_start, left_end), (right_start, universe_end)]` of the region `(universe_start, universe_end)` after removing `(region_start, region_end)`.
+        /// A remainder is empty (has equal start and end) if the sub-region touches the respective end of the region.
+        /// The parts of the sub-region that lie outside the region are ignored.
+        /// If the region and sub-region are aligned, so are the remainders.
+        ///
+        /// Panics if `universe_start > universe_end`.
+        #[inline]
+        pub const fn $align_region_complement(
+            universe_start: $u,
+            universe_end: $u,
+            region_start: $u,
+            region_end: $u,
+        ) -> [($u, $u); 2] {
+            assert!(
+                universe_start <= universe_end,
+                "`universe_start` must not be greater than `universe_end`"
+            );
+            let left_end = if region_start < universe_start {
+                universe_start
+            } else if region_start > universe_end {
+                universe_end
+            } else {
+                region_start
+            };
+            let right_start = if region_end < left_end {
+                left_end
+            } else if region_end > universe_end {
+                universe_end
+            } else {
+                region_end
+            };
+            [(universe_start, left_end), (right_start, universe_end)]
+        }
+    };
+}
+
+align_region_complement_impl!(u8, u8_align_region_complement);
+align_region_complement_impl!(u16, u16_align_region_complement);
+align_region_complement_impl!(u32, u32_align_region_complement);
+align_region_complement_impl!(u64, u64_align_region_complement);
+align_region_complement_impl!(u128, u128_align_region_complement);
+align_region_complement_impl!(usize, usize_align_region_complement);
+
+macro_rules! align_natural_size_impl {
+    ($u:ty, $next_power_of_two_size:ident) => {
+        /// Round a size up to a naturally aligned size.
+        ///
+        /// Returns the smallest power of two `x` so that `x >= size`, which can serve as both the size and the alignment of a naturally aligned allocation, for example for DMA.
+        /// Returns `None` if an overflow occurs.
+        #[inline]
+        pub const fn $next_power_of_two_size(size: $u) -> Option<$u> {
+            size.checked_next_power_of_two()
+        }
+    };
+}
+
+align_natural_size_impl!(u8, u8_next_power_of_two_size);
+align_natural_size_impl!(u16, u16_next_power_of_two_size);
+align_natural_size_impl!(u32, u32_next_power_of_two_size);
+align_natural_size_impl!(u64, u64_next_power_of_two_size);
+align_natural_size_impl!(u128, u128_next_power_of_two_size);
+align_natural_size_impl!(usize, usize_next_power_of_two_size);
+
+macro_rules! align_naturally_aligned_impl {
+    ($u:ty, $is_aligned_to:ident, $is_naturally_aligned:ident) => {
+        /// Checks whether the block is naturally aligned, i.e., whether `base` has alignment `size`.
+        ///
+        /// Many DMA controllers require naturally aligned blocks.
+        ///
+        /// Panics if the size is not a power of two.
+        #[inline]
+        pub const fn $is_naturally_aligned(base: $u, size: $u) -> bool {
+            assert_power_of_two!(size, "`size` must be a power of two");
+            $is_aligned_to(base, size)
+        }
+    };
+}
+
+align_naturally_aligned_impl!(u8, u8_is_aligned_to, u8_is_naturally_aligned);
+align_naturally_aligned_impl!(u16, u16_is_aligned_to, u16_is_naturally_aligned);
+align_naturally_aligned_impl!(u32, u32_is_aligned_to, u32_is_naturally_aligned);
+align_naturally_aligned_impl!(u64, u64_is_aligned_to, u64_is_naturally_aligned);
+align_naturally_aligned_impl!(u128, u128_is_aligned_to, u128_is_naturally_aligned);
+align_naturally_aligned_impl!(usize, usize_is_aligned_to, usize_is_naturally_aligned);
+
+macro_rules! align_const_impl {
+    ($u:ty, $align_down:ident, $align_up:ident, $align_down_checked_const:ident, $align_up_checked_const:ident) => {
+        /// Align address downwards to a constant alignment.
+        ///
+        /// Returns the greatest `x` with alignment `ALIGN` so that `x <= addr`.
+        ///
+        /// Fails to compile if the alignment is not a power of two.
+        #[inline]
+        pub const fn $align_down_checked_const<const ALIGN: $u>(addr: $u) -> $u {
+            const { assert!(ALIGN.is_power_of_two(), "`ALIGN` must be a power of two") };
+            $align_down(addr, ALIGN)
+        }
+
+        /// Align address upwards to a constant alignment.
+        ///
+        /// Returns the smallest `x` with alignment `ALIGN` so that `x >= addr`.
+        ///
+        /// Fails to compile if the alignment is not a power of two.
+        /// Panics if an overflow occurs.
+        #[inline]
+        pub const fn $align_up_checked_const<const ALIGN: $u>(addr: $u) -> $u {
+            const { assert!(ALIGN.is_power_of_two(), "`ALIGN` must be a power of two") };
+            $align_up(addr, ALIGN)
+        }
+    };
+}
+
+align_const_impl!(
+    u8,
+    u8_align_down,
+    u8_align_up,
+    u8_align_down_checked_const,
+    u8_align_up_checked_const
+);
+align_const_impl!(
+    u16,
+    u16_align_down,
+    u16_align_up,
+    u16_align_down_checked_const,
+    u16_align_up_checked_const
+);
+align_const_impl!(
+    u32,
+    u32_align_down,
+    u32_align_up,
+    u32_align_down_checked_const,
+    u32_align_up_checked_const
+);
+align_const_impl!(
+    u64,
+    u64_align_down,
+    u64_align_up,
+    u64_align_down_checked_const,
+    u64_align_up_checked_const
+);
+align_const_impl!(
+    u128,
+    u128_align_down,
+    u128_align_up,
+    u128_align_down_checked_const,
+    u128_align_up_checked_const
+);
+align_const_impl!(
+    usize,
+    usize_align_down,
+    usize_align_up,
+    usize_align_down_checked_const,
+    usize_align_up_checked_const
+);
+
+macro_rules! align_find_gap_impl {
+    ($u:ty, $checked_align_up:ident, $align_region_find_gap:ident) => {
+        /// Finds the first gap between `(base, end)` regions that fits an aligned allocation.
+        ///
+        /// `regions` are occupied regions sorted by base.
+        /// Returns the smallest `x` with alignment `align` in the first gap between two consecutive regions so that `[x, x + min_size)` fits into the gap, as in a first-fit allocator.
+        /// Space before the first and after the last region is not considered, so the managed area can be bounded by empty regions.
+        /// Returns `None` if no gap fits the allocation.
+        ///
+        /// Panics if the alignment is not a power of two.
+        #[inline]
+        pub const fn $align_region_find_gap(
+            regions: &[($u, $u)],
+            min_size: $u,
+            align: $u,
+        ) -> Option<$u> {
+            let mut i = 1;
+            while i < regions.len() {
+                let gap_end = regions[i].0;
+                if let Some(base) = $checked_align_up(regions[i - 1].1, align) {
+                    if base <= gap_end && gap_end - base >= min_size {
+                        return Some(base);
+                    }
+                }
+                i += 1;
+            }
+            None
+        }
+    };
+}
+
+align_find_gap_impl!(u8, u8_checked_align_up, u8_align_region_find_gap);
+align_find_gap_impl!(u16, u16_checked_align_up, u16_align_region_find_gap);
+align_find_gap_impl!(u32, u32_checked_align_up, u32_align_region_find_gap);
+align_find_gap_impl!(u64, u64_checked_align_up, u64_align_region_find_gap);
+align_find_gap_impl!(u128, u128_checked_align_up, u128_align_region_find_gap);
+align_find_gap_impl!(usize, usize_checked_align_up, usize_align_region_find_gap);
+
+macro_rules! align_table_entry_impl {
+    ($u:ty, $align_up_table_entry:ident) => {
+        /// Returns the address of the `index`th entry in a table of `entry_size`-byte entries.
+        ///
+        /// This is `table_addr + index * entry_size`, for example for hardware descriptor tables.
+        /// Returns `None` if an overflow occurs.
+        ///
+        /// Panics if the entry size is not a power of two.
+        #[inline]
+        pub const fn $align_up_table_entry(
+            table_addr: $u,
+            index: $u,
+            entry_size: $u,
+        ) -> Option<$u> {
+            assert_power_of_two!(entry_size, "`entry_size` must be a power of two");
+            let Some(offset) = index.checked_mul(entry_size) else {
+                return None;
+            };
+            table_addr.checked_add(offset)
+        }
+    };
+}
+
+align_table_entry_impl!(u8, u8_align_up_table_entry);
+align_table_entry_impl!(u16, u16_align_up_table_entry);
+align_table_entry_impl!(u32, u32_align_up_table_entry);
+align_table_entry_impl!(u64, u64_align_up_table_entry);
+align_table_entry_impl!(u128, u128_align_up_table_entry);
+align_table_entry_impl!(usize, usize_align_up_table_entry);
+
+macro_rules! align_offset_check_impl {
+    ($u:ty, $checked_align_up:ident, $align_up_with_offset_check:ident) => {
+        /// Align address upwards with bounded padding.
+        ///
+        /// Returns the smallest `x` with alignment `align` so that `x >= addr`,
+        /// or `None` if `x - addr > max_offset` or if an overflow occurs.
+        ///
+        /// Panics if the alignment is not a power of two.
+        #[inline]
+        pub const fn $align_up_with_offset_check(
+            addr: $u,
+            align: $u,
+            max_offset: $u,
+        ) -> Option<$u> {
+            let Some(aligned) = $checked_align_up(addr, align) else {
+                return None;
+            };
+            if aligned - addr > max_offset {
+                return None;
+            }
+            Some(aligned)
+        }
+    };
+}
+
+align_offset_check_impl!(u8, u8_checked_align_up, u8_align_up_with_offset_check);
+align_offset_check_impl!(u16, u16_checked_align_up, u16_align_up_with_offset_check);
+align_offset_check_impl!(u32, u32_checked_align_up, u32_align_up_with_offset_check);
+align_offset_check_impl!(u64, u64_checked_align_up, u64_align_up_with_offset_check);
+align_offset_check_impl!(u128, u128_checked_align_up, u128_align_up_with_offset_check);
+align_offset_check_impl!(
+    usize,
+    usize_checked_align_up,
+    usize_align_up_with_offset_check
+);
+
+macro_rules! align_mod_impl {
+    (
+        $u:ident,
+        $align_down:ident,
+        $align_up:ident,
+        $checked_align_up:ident,
+        $saturating_align_up:ident,
+        $is_aligned_to:ident,
+        $align_to_nearest:ident,
+        $offset_to_aligned:ident,
+        $alignment_order:ident,
+        $align_down_clamp:ident,
+        $align_up_clamp:ident
+    ) => {
+        #[doc = concat!("Alignment functions for [`", stringify!($u), "`](prim@", stringify!($u), ") without the type prefix.")]
+        ///
+        /// This allows concise call sites with glob imports.
+        pub mod $u {
+            pub use crate::{
+                $align_down as align_down,
+                $align_up as align_up,
+                $checked_align_up as checked_align_up,
+                $saturating_align_up as saturating_align_up,
+                $is_aligned_to as is_aligned_to,
+                $align_to_nearest as align_to_nearest,
+                $offset_to_aligned as offset_to_aligned,
+                $alignment_order as alignment_order,
+                $align_down_clamp as align_down_clamp,
+                $align_up_clamp as align_up_clamp,
+            };
+        }
+    };
+}
+
+align_mod_impl!(
+    u8,
+    u8_align_down,
+    u8_align_up,
+    u8_checked_align_up,
+    u8_saturating_align_up,
+    u8_is_aligned_to,
+    u8_align_to_nearest,
+    u8_offset_to_aligned,
+    u8_alignment_order,
+    u8_align_down_clamp,
+    u8_align_up_clamp
+);
+align_mod_impl!(
+    u16,
+    u16_align_down,
+    u16_align_up,
+    u16_checked_align_up,
+    u16_saturating_align_up,
+    u16_is_aligned_to,
+    u16_align_to_nearest,
+    u16_offset_to_aligned,
+    u16_alignment_order,
+    u16_align_down_clamp,
+    u16_align_up_clamp
+);
+align_mod_impl!(
+    u32,
+    u32_align_down,
+    u32_align_up,
+    u32_checked_align_up,
+    u32_saturating_align_up,
+    u32_is_aligned_to,
+    u32_align_to_nearest,
+    u32_offset_to_aligned,
+    u32_alignment_order,
+    u32_align_down_clamp,
+    u32_align_up_clamp
+);
+align_mod_impl!(
+    u64,
+    u64_align_down,
+    u64_align_up,
+    u64_checked_align_up,
+    u64_saturating_align_up,
+    u64_is_aligned_to,
+    u64_align_to_nearest,
+    u64_offset_to_aligned,
+    u64_alignment_order,
+    u64_align_down_clamp,
+    u64_align_up_clamp
+);
+align_mod_impl!(
+    u128,
+    u128_align_down,
+    u128_align_up,
+    u128_checked_align_up,
+    u128_saturating_align_up,
+    u128_is_aligned_to,
+    u128_align_to_nearest,
+    u128_offset_to_aligned,
+    u128_alignment_order,
+    u128_align_down_clamp,
+    u128_align_up_clamp
+);
+align_mod_impl!(
+    usize,
+    usize_align_down,
+    usize_align_up,
+    usize_checked_align_up,
+    usize_saturating_align_up,
+    usize_is_aligned_to,
+    usize_align_to_nearest,
+    usize_offset_to_aligned,
+    usize_alignment_order,
+    usize_align_down_clamp,
+    usize_align_up_clamp
+);
+
+macro_rules! align_report_impl {
+    ($u:ty, $align_up:ident, $align_up_report:ident) => {
+        /// Align address upwards and report the padding.
+        ///
+        /// Returns `(x, x - addr)`, where `x` is the smallest `x` with alignment `align` so that `x >= addr`, for example for accounting in allocators.
+        ///
+        /// Panics if the alignment is not a power of two or if an overflow occurs.
+        #[inline]
+        pub const fn $align_up_report(addr: $u, align: $u) -> ($u, $u) {
+            let aligned = $align_up(addr, align);
+            (aligned, aligned - addr)
+        }
+    };
+}
+
+align_report_impl!(u8, u8_align_up, u8_align_up_report);
+align_report_impl!(u16, u16_align_up, u16_align_up_report);
+align_report_impl!(u32, u32_align_up, u32_align_up_report);
+align_report_impl!(u64, u64_align_up, u64_align_up_report);
+align_report_impl!(u128, u128_align_up, u128_align_up_report);
+align_report_impl!(usize, usize_align_up, usize_align_up_report);
+
+// Adapted from `x86_64`
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Asserts that aligning is a round trip: an aligned address stays the same when aligned again.
+    fn align_round_trip_check<T>(addr: T, align: T)
+    where
+        T: Align + core::fmt::Debug + PartialOrd,
+    {
+        let up = addr.align_up(align);
+        assert!(up >= addr);
+        assert_eq!(up.align_down(align), up);
+        assert_eq!(up.align_up(align), up);
+        let down = addr.align_down(align);
+        assert!(down <= addr);
+        assert_eq!(down.align_up(align), down);
+        assert_eq!(down.align_down(align), down);
+    }
+
+    macro_rules! test_align_up_impl {
+        ($u:ty, $align_up:ident, $test_align_up:ident) => {
+            #[test]
+            fn $test_align_up() {
+                // align 1
+                assert_eq!($align_up(0, 1), 0);
+                assert_eq!($align_up(123, 1), 123);
+                assert_eq!($align_up(<$u>::MAX, 1), <$u>::MAX);
+                // align 2
+                assert_eq!($align_up(0, 2), 0);
+                assert_eq!($align_up(123, 2), 124);
+                assert_eq!($align_up(<$u>::MAX - 1, 2), <$u>::MAX - 1);
+                // address 0
+                assert_eq!($align_up(0, 128), 0);
+                assert_eq!($align_up(0, 1), 0);
+                assert_eq!($align_up(0, 2), 0);
+                assert_eq!($align_up(0, <$u>::MAX & 1 << (<$u>::BITS - 1)), 0);
+                // round trip
+                align_round_trip_check::<$u>(123, 1);
+                align_round_trip_check::<$u>(123, 2);
+                align_round_trip_check::<$u>(<$u>::MAX - 1, 2);
+                align_round_trip_check::<$u>(0, <$u>::MAX & 1 << (<$u>::BITS - 1));
+            }
+        };
+    }
+
+    test_align_up_impl!(u8, u8_align_up, test_u8_align_up);
+    test_align_up_impl!(u16, u16_align_up, test_u16_align_up);
+    test_align_up_impl!(u32, u32_align_up, test_u32_align_up);
+    test_align_up_impl!(u64, u64_align_up, test_u64_align_up);
+    test_align_up_impl!(u128, u128_align_up, test_u128_align_up);
+    test_align_up_impl!(usize, usize_align_up, test_usize_align_up);
+
+    macro_rules! test_align_up_overflow_impl {
+        ($u:ty, $test_align_up_overflow:ident, $two:expr) => {
+            #[test]
+            #[should_panic]
+            fn $test_align_up_overflow() {
+                <$u>::MAX.align_up($two);
+            }
+        };
+    }
+
+    test_align_up_overflow_impl!(u8, test_u8_align_up_overflow, 2);
+    test_align_up_overflow_impl!(u16, test_u16_align_up_overflow, 2);
+    test_align_up_overflow_impl!(u32, test_u32_align_up_overflow, 2);
+    test_align_up_overflow_impl!(u64, test_u64_align_up_overflow, 2);
+    test_align_up_overflow_impl!(u128, test_u128_align_up_overflow, 2);
+    test_align_up_overflow_impl!(usize, test_usize_align_up_overflow, 2);
+
+    macro_rules! test_checked_align_up_impl {
+        ($u:ty, $checked_align_up:ident, $test_checked_align_up:ident) => {
+            #[test]
+            fn $test_checked_align_up() {
+                assert_eq!($checked_align_up(0, 1), Some(0));
+                assert_eq!($checked_align_up(123, 2), Some(124));
+                assert_eq!($checked_align_up(<$u>::MAX - 1, 2), Some(<$u>::MAX - 1));
+                assert_eq!($checked_align_up(<$u>::MAX, 1), Some(<$u>::MAX));
+                assert_eq!($checked_align_up(<$u>::MAX, 2), None);
+            }
+        };
+    }
+
+    test_checked_align_up_impl!(u8, u8_checked_align_up, test_u8_checked_align_up);
+    test_checked_align_up_impl!(u16, u16_checked_align_up, test_u16_checked_align_up);
+    test_checked_align_up_impl!(u32, u32_checked_align_up, test_u32_checked_align_up);
+    test_checked_align_up_impl!(u64, u64_checked_align_up, test_u64_checked_align_up);
+    test_checked_align_up_impl!(u128, u128_checked_align_up, test_u128_checked_align_up);
+    test_checked_align_up_impl!(usize, usize_checked_align_up, test_usize_checked_align_up);
+
+    macro_rules! test_align_up_to_next_aligned_after_impl {
+        ($u:ty, $align_up_to_next_aligned_after:ident, $test_align_up_to_next_aligned_after:ident) => {
+            #[test]
+            fn $test_align_up_to_next_aligned_after() {
+                // `after` below `addr`
+                assert_eq!($align_up_to_next_aligned_after(5, 4, 2), Some(8));
+                assert_eq!($align_up_to_next_aligned_after(8, 4, 2), Some(8));
+                // `after` is the aligned address
+                assert_eq!($align_up_to_next_aligned_after(8, 4, 8), Some(12));
+                // `after` above `addr`
+                assert_eq!($align_up_to_next_aligned_after(0, 4, 9), Some(12));
+                // overflow
+                assert_eq!($align_up_to_next_aligned_after(<$u>::MAX, 2, 0), None);
+                assert_eq!($align_up_to_next_aligned_after(0, 2, <$u>::MAX), None);
+                assert_eq!($align_up_to_next_aligned_after(0, 2, <$u>::MAX - 1), None);
+            }
+        };
+    }
+
+    test_align_up_to_next_aligned_after_impl!(
+        u8,
+        u8_align_up_to_next_aligned_after,
+        test_u8_align_up_to_next_aligned_after
+    );
+    test_align_up_to_next_aligned_after_impl!(
+        u16,
+        u16_align_up_to_next_aligned_after,
+        test_u16_align_up_to_next_aligned_after
+    );
+    test_align_up_to_next_aligned_after_impl!(
+        u32,
+        u32_align_up_to_next_aligned_after,
+        test_u32_align_up_to_next_aligned_after
+    );
+    test_align_up_to_next_aligned_after_impl!(
+        u64,
+        u64_align_up_to_next_aligned_after,
+        test_u64_align_up_to_next_aligned_after
+    );
+    test_align_up_to_next_aligned_after_impl!(
+        u128,
+        u128_align_up_to_next_aligned_after,
+        test_u128_align_up_to_next_aligned_after
+    );
+    test_align_up_to_next_aligned_after_impl!(
+        usize,
+        usize_align_up_to_next_aligned_after,
+        test_usize_align_up_to_next_aligned_after
+    );
+
+    macro_rules! test_align_down_to_prev_aligned_before_impl {
+        ($u:ty, $align_down_to_prev_aligned_before:ident, $test_align_down_to_prev_aligned_before:ident) => {
+            #[test]
+            fn $test_align_down_to_prev_aligned_before() {
+                // `before` above `addr`
+                assert_eq!($align_down_to_prev_aligned_before(7, 4, 12), Some(4));
+                assert_eq!(
+                    $align_down_to_prev_aligned_before(<$u>::MAX, 1, <$u>::MAX),
+                    Some(<$u>::MAX - 1)
+                );
+                // `before` is the aligned address
+                assert_eq!($align_down_to_prev_aligned_before(8, 4, 8), Some(4));
+                // `before` below `addr`
+                assert_eq!($align_down_to_prev_aligned_before(<$u>::MAX, 4, 9), Some(8));
+                // zero boundary
+                assert_eq!($align_down_to_prev_aligned_before(7, 4, 1), Some(0));
+                assert_eq!($align_down_to_prev_aligned_before(0, 4, 0), None);
+                assert_eq!($align_down_to_prev_aligned_before(7, 4, 0), None);
+            }
+        };
+    }
+
+    test_align_down_to_prev_aligned_before_impl!(
+        u8,
+        u8_align_down_to_prev_aligned_before,
+        test_u8_align_down_to_prev_aligned_before
+    );
+    test_align_down_to_prev_aligned_before_impl!(
+        u16,
+        u16_align_down_to_prev_aligned_before,
+        test_u16_align_down_to_prev_aligned_before
+    );
+    test_align_down_to_prev_aligned_before_impl!(
+        u32,
+        u32_align_down_to_prev_aligned_before,
+        test_u32_align_down_to_prev_aligned_before
+    );
+    test_align_down_to_prev_aligned_before_impl!(
+        u64,
+        u64_align_down_to_prev_aligned_before,
+        test_u64_align_down_to_prev_aligned_before
+    );
+    test_align_down_to_prev_aligned_before_impl!(
+        u128,
+        u128_align_down_to_prev_aligned_before,
+        test_u128_align_down_to_prev_aligned_before
+    );
+    test_align_down_to_prev_aligned_before_impl!(
+        usize,
+        usize_align_down_to_prev_aligned_before,
+        test_usize_align_down_to_prev_aligned_before
+    );
+
+    macro_rules! test_align_intersection_impl {
+        ($u:ty, $align_intersection:ident, $test_align_intersection:ident) => {
+            #[test]
+            fn $test_align_intersection() {
+                assert_eq!($align_intersection(&[]), 1);
+                assert_eq!($align_intersection(&[0]), 1 << (<$u>::BITS - 1));
+                assert_eq!($align_intersection(&[0, 64]), 64);
+                assert_eq!($align_intersection(&[16, 48, 80]), 16);
+                assert_eq!($align_intersection(&[16, 48, 81]), 1);
+                assert_eq!($align_intersection(&[<$u>::MAX]), 1);
+            }
+        };
+    }
+
+    test_align_intersection_impl!(u8, u8_align_intersection, test_u8_align_intersection);
+    test_align_intersection_impl!(u16, u16_align_intersection, test_u16_align_intersection);
+    test_align_intersection_impl!(u32, u32_align_intersection, test_u32_align_intersection);
+    test_align_intersection_impl!(u64, u64_align_intersection, test_u64_align_intersection);
+    test_align_intersection_impl!(u128, u128_align_intersection, test_u128_align_intersection);
+    test_align_intersection_impl!(
+        usize,
+        usize_align_intersection,
+        test_usize_align_intersection
+    );
+
+    #[test]
+    fn test_const_array_len() {
+        const SIZE: usize = 1500;
+        const PAGE_ALIGN: usize = 4096;
+
+        struct Buffer<const N: usize>([u8; N]);
+
+        let frame: [u8; usize_align_up(SIZE, PAGE_ALIGN)] = [0; usize_align_up(SIZE, PAGE_ALIGN)];
+        let buffer: Buffer<{ usize_align_up(SIZE, 64) }> = Buffer([0; 1536]);
+        assert_eq!(frame.len(), PAGE_ALIGN);
+        assert_eq!(buffer.0.len(), 1536);
+    }
+
+    macro_rules! test_align_scale_impl {
+        ($u:ty, $align_down_halved:ident, $align_up_doubled:ident, $test_align_scale:ident) => {
+            #[test]
+            fn $test_align_scale() {
+                assert_eq!($align_down_halved(7, 8), 4);
+                assert_eq!($align_down_halved(7, 2), 7);
+                assert_eq!($align_down_halved(<$u>::MAX, 4), <$u>::MAX - 1);
+                assert_eq!($align_up_doubled(5, 4), Some(8));
+                assert_eq!($align_up_doubled(8, 4), Some(8));
+                assert_eq!($align_up_doubled(0, 1 << (<$u>::BITS - 2)), Some(0));
+                assert_eq!($align_up_doubled(0, 1 << (<$u>::BITS - 1)), None);
+                assert_eq!($align_up_doubled(<$u>::MAX, 1), None);
+            }
+        };
+    }
+
+    test_align_scale_impl!(
+        u8,
+        u8_align_down_halved,
+        u8_align_up_doubled,
+        test_u8_align_scale
+    );
+    test_align_scale_impl!(
+        u16,
+        u16_align_down_halved,
+        u16_align_up_doubled,
+        test_u16_align_scale
+    );
+    test_align_scale_impl!(
+        u32,
+        u32_align_down_halved,
+        u32_align_up_doubled,
+        test_u32_align_scale
+    );
+    test_align_scale_impl!(
+        u64,
+        u64_align_down_halved,
+        u64_align_up_doubled,
+        test_u64_align_scale
+    );
+    test_align_scale_impl!(
+        u128,
+        u128_align_down_halved,
+        u128_align_up_doubled,
+        test_u128_align_scale
+    );
+    test_align_scale_impl!(
+        usize,
+        usize_align_down_halved,
+        usize_align_up_doubled,
+        test_usize_align_scale
+    );
+
+    macro_rules! test_align_down_halved_too_small_impl {
+        ($u:ty, $align_down_halved:ident, $test_align_down_halved_too_small:ident) => {
+            #[test]
+            #[should_panic]
+            fn $test_align_down_halved_too_small() {
+                $align_down_halved(123, 1);
+            }
+        };
+    }
+
+    test_align_down_halved_too_small_impl!(
+        u8,
+        u8_align_down_halved,
+        test_u8_align_down_halved_too_small
+    );
+    test_align_down_halved_too_small_impl!(
+        u16,
+        u16_align_down_halved,
+        test_u16_align_down_halved_too_small
+    );
+    test_align_down_halved_too_small_impl!(
+        u32,
+        u32_align_down_halved,
+        test_u32_align_down_halved_too_small
+    );
+    test_align_down_halved_too_small_impl!(
+        u64,
+        u64_align_down_halved,
+        test_u64_align_down_halved_too_small
+    );
+    test_align_down_halved_too_small_impl!(
+        u128,
+        u128_align_down_halved,
+        test_u128_align_down_halved_too_small
+    );
+    test_align_down_halved_too_small_impl!(
+        usize,
+        usize_align_down_halved,
+        test_usize_align_down_halved_too_small
+    );
+
+    macro_rules! test_align_wrapper_impl {
+        ($u:ty, $test_align_wrapper:ident) => {
+            #[test]
+            fn $test_align_wrapper() {
+                assert_eq!(Wrapping::<$u>(123).align_down(Wrapping(2)), Wrapping(122));
+                assert_eq!(Wrapping::<$u>(123).align_up(Wrapping(2)), Wrapping(124));
+                assert_eq!(Wrapping(<$u>::MAX).align_up(Wrapping(2)), Wrapping(0));
+                assert!(Wrapping::<$u>(124).is_aligned_to(Wrapping(4)));
+                assert_eq!(
+                    Saturating::<$u>(123).align_down(Saturating(2)),
+                    Saturating(122)
+                );
+                assert_eq!(
+                    Saturating::<$u>(123).align_up(Saturating(2)),
+                    Saturating(124)
+                );
+                assert_eq!(
+                    Saturating(<$u>::MAX).align_up(Saturating(2)),
+                    Saturating(<$u>::MAX)
+                );
+                assert!(Saturating::<$u>(124).is_aligned_to(Saturating(4)));
+            }
+        };
+    }
+
+    test_align_wrapper_impl!(u8, test_u8_align_wrapper);
+    test_align_wrapper_impl!(u16, test_u16_align_wrapper);
+    test_align_wrapper_impl!(u32, test_u32_align_wrapper);
+    test_align_wrapper_impl!(u64, test_u64_align_wrapper);
+    test_align_wrapper_impl!(u128, test_u128_align_wrapper);
+    test_align_wrapper_impl!(usize, test_usize_align_wrapper);
+
+    #[test]
+    fn test_u64_align_truncate_u32() {
+        assert_eq!(u64_align_down_truncate_u32(0x1234, 0x1000), Some(0x1000));
+        assert_eq!(
+            u64_align_down_truncate_u32(u32::MAX.into(), 2),
+            Some(u32::MAX - 1)
+        );
+        assert_eq!(u64_align_down_truncate_u32(1 << 32, 0x1000), None);
+        assert_eq!(u64_align_up_truncate_u32(0x1234, 0x1000), Some(0x2000));
+        assert_eq!(
+            u64_align_up_truncate_u32(u32::MAX.into(), 1),
+            Some(u32::MAX)
+        );
+        assert_eq!(u64_align_up_truncate_u32(u32::MAX.into(), 2), None);
+        assert_eq!(u64_align_up_truncate_u32(u64::MAX, 2), None);
+    }
+
+    macro_rules! test_align_offset_impl {
+        ($u:ty, $align_up_add:ident, $align_down_sub:ident, $test_align_offset:ident) => {
+            #[test]
+            fn $test_align_offset() {
+                assert_eq!($align_up_add(100, 23, 2), Some(124));
+                assert_eq!($align_up_add(<$u>::MAX - 1, 0, 2), Some(<$u>::MAX - 1));
+                assert_eq!($align_up_add(<$u>::MAX - 1, 1, 2), None);
+                assert_eq!($align_up_add(<$u>::MAX, 1, 1), None);
+                assert_eq!($align_down_sub(100, 23, 2), Some(76));
+                assert_eq!($align_down_sub(<$u>::MAX, 0, 2), Some(<$u>::MAX - 1));
+                assert_eq!($align_down_sub(1, 1, 2), Some(0));
+                assert_eq!($align_down_sub(1, 2, 1), None);
+            }
+        };
+    }
+
+    test_align_offset_impl!(u8, u8_align_up_add, u8_align_down_sub, test_u8_align_offset);
+    test_align_offset_impl!(
+        u16,
+        u16_align_up_add,
+        u16_align_down_sub,
+        test_u16_align_offset
+    );
+    test_align_offset_impl!(
+        u32,
+        u32_align_up_add,
+        u32_align_down_sub,
+        test_u32_align_offset
+    );
+    test_align_offset_impl!(
+        u64,
+        u64_align_up_add,
+        u64_align_down_sub,
+        test_u64_align_offset
+    );
+    test_align_offset_impl!(
+        u128,
+        u128_align_up_add,
+        u128_align_down_sub,
+        test_u128_align_offset
+    );
+    test_align_offset_impl!(
+        usize,
+        usize_align_up_add,
+        usize_align_down_sub,
+        test_usize_align_offset
+    );
+
+    macro_rules! test_align_to_nearest_impl {
+        ($u:ty, $align_to_nearest:ident, $test_align_to_nearest:ident) => {
+            #[test]
+            fn $test_align_to_nearest() {
+                // align 1
+                assert_eq!($align_to_nearest(123, 1), 123);
+                assert_eq!($align_to_nearest(<$u>::MAX, 1), <$u>::MAX);
+                // round down
+                assert_eq!($align_to_nearest(65, 8), 64);
+                assert_eq!($align_to_nearest(67, 8), 64);
+                // tie
+                assert_eq!($align_to_nearest(68, 8), 72);
+                assert_eq!($align_to_nearest(1, 2), 2);
+                // round up
+                assert_eq!($align_to_nearest(71, 8), 72);
+                assert_eq!($align_to_nearest(<$u>::MAX - 2, 4), <$u>::MAX - 3);
+                // trait and wrappers
+                assert_eq!((67 as $u).align_to_nearest(8), 64);
+                assert_eq!(
+                    Wrapping(<$u>::MAX).align_to_nearest(Wrapping(2)),
+                    Wrapping(0)
+                );
+                assert_eq!(
+                    Saturating(<$u>::MAX).align_to_nearest(Saturating(2)),
+                    Saturating(<$u>::MAX)
+                );
+            }
+        };
+    }
+
+    test_align_to_nearest_impl!(u8, u8_align_to_nearest, test_u8_align_to_nearest);
+    test_align_to_nearest_impl!(u16, u16_align_to_nearest, test_u16_align_to_nearest);
+    test_align_to_nearest_impl!(u32, u32_align_to_nearest, test_u32_align_to_nearest);
+    test_align_to_nearest_impl!(u64, u64_align_to_nearest, test_u64_align_to_nearest);
+    test_align_to_nearest_impl!(u128, u128_align_to_nearest, test_u128_align_to_nearest);
+    test_align_to_nearest_impl!(usize, usize_align_to_nearest, test_usize_align_to_nearest);
+
+    #[cfg(feature = "ssz")]
+    #[test]
+    fn test_ssz() {
+        use ssz::*;
+
+        assert_eq!(align_up_to_ssz_field(0), 0);
+        assert_eq!(align_up_to_ssz_field(5), 8);
+        assert_eq!(align_up_to_ssz_list(5), 32);
+        assert_eq!(align_up_to_ssz_list(64), 64);
+    }
+
+    macro_rules! test_align_coverage_log2_impl {
+        ($u:ty, $align_coverage_log2:ident, $test_align_coverage_log2:ident) => {
+            #[test]
+            fn $test_align_coverage_log2() {
+                assert_eq!($align_coverage_log2(0), 0);
+                assert_eq!($align_coverage_log2(1), 0);
+                assert_eq!($align_coverage_log2(2), 1);
+                assert_eq!($align_coverage_log2(3), 2);
+                assert_eq!($align_coverage_log2(128), 7);
+                assert_eq!($align_coverage_log2(129), 8);
+                assert_eq!($align_coverage_log2(1 << (<$u>::BITS - 1)), <$u>::BITS - 1);
+                assert_eq!($align_coverage_log2(<$u>::MAX), <$u>::BITS);
+            }
+        };
+    }
+
+    test_align_coverage_log2_impl!(u8, u8_align_coverage_log2, test_u8_align_coverage_log2);
+    test_align_coverage_log2_impl!(u16, u16_align_coverage_log2, test_u16_align_coverage_log2);
+    test_align_coverage_log2_impl!(u32, u32_align_coverage_log2, test_u32_align_coverage_log2);
+    test_align_coverage_log2_impl!(u64, u64_align_coverage_log2, test_u64_align_coverage_log2);
+    test_align_coverage_log2_impl!(
+        u128,
+        u128_align_coverage_log2,
+        test_u128_align_coverage_log2
+    );
+    test_align_coverage_log2_impl!(
+        usize,
+        usize_align_coverage_log2,
+        test_usize_align_coverage_log2
+    );
+
+    #[test]
+    fn test_align_coverage_log2_mtu() {
+        assert_eq!(u16_align_coverage_log2(1500), 11);
+        assert_eq!(usize_align_coverage_log2(1500), 11);
+    }
+
+    #[test]
+    fn test_static_assert_aligned() {
+        #[repr(align(64))]
+        struct CacheLine;
+
+        static_assert_aligned!(CacheLine, 64);
+        static_assert_aligned!(CacheLine, 1);
+        static_assert_aligned!(u8, 1);
+    }
+
+    macro_rules! test_align_region_valid_impl {
+        ($u:ty, $align_region_valid:ident, $test_align_region_valid:ident) => {
+            #[test]
+            fn $test_align_region_valid() {
+                assert!($align_region_valid(0x10, 0x30, 0x10));
+                assert!($align_region_valid(0, 0, 0x10));
+                assert!($align_region_valid(<$u>::MAX - 0xf, 0, 0x10));
+                // misaligned base
+                assert!(!$align_region_valid(0x18, 0x30, 0x10));
+                // misaligned size
+                assert!(!$align_region_valid(0x10, 0x38, 0x10));
+                // overflow
+                assert!(!$align_region_valid(<$u>::MAX - 0xf, 0x10, 0x10));
+            }
+        };
+    }
+
+    test_align_region_valid_impl!(u8, u8_align_region_valid, test_u8_align_region_valid);
+    test_align_region_valid_impl!(u16, u16_align_region_valid, test_u16_align_region_valid);
+    test_align_region_valid_impl!(u32, u32_align_region_valid, test_u32_align_region_valid);
+    test_align_region_valid_impl!(u64, u64_align_region_valid, test_u64_align_region_valid);
+    test_align_region_valid_impl!(u128, u128_align_region_valid, test_u128_align_region_valid);
+    test_align_region_valid_impl!(
+        usize,
+        usize_align_region_valid,
+        test_usize_align_region_valid
+    );
+
+    #[test]
+    fn test_align_up_saturating_cast() {
+        assert_eq!(u16_align_up_saturating_cast_u8(123, 2), 124);
+        assert_eq!(u16_align_up_saturating_cast_u8(255, 1), 255);
+        assert_eq!(u16_align_up_saturating_cast_u8(255, 2), u8::MAX);
+        assert_eq!(u64_align_up_saturating_cast_u32(0x1234, 0x1000), 0x2000);
+        assert_eq!(u64_align_up_saturating_cast_u32(u64::MAX, 2), u32::MAX);
+        assert_eq!(u128_align_up_saturating_cast_usize(0x1234, 0x1000), 0x2000);
+        assert_eq!(
+            u128_align_up_saturating_cast_usize(u128::MAX, 1),
+            usize::MAX
+        );
+        assert_eq!(
+            u128_align_up_saturating_cast_u64(u64::MAX.into(), 2),
+            u64::MAX
+        );
+        assert_eq!(usize_align_up_saturating_cast_u16(0x1234, 0x1000), 0x2000);
+    }
+
+    macro_rules! test_align_up_with_stride_and_base_impl {
+        ($u:ty, $align_up_with_stride_and_base:ident, $test_align_up_with_stride_and_base:ident) => {
+            #[test]
+            fn $test_align_up_with_stride_and_base() {
+                // `addr` below `base`
+                assert_eq!($align_up_with_stride_and_base(0, 3, 8), Some(3));
+                assert_eq!($align_up_with_stride_and_base(3, 3, 8), Some(3));
+                assert_eq!($align_up_with_stride_and_base(4, 3, 8), Some(11));
+                assert_eq!($align_up_with_stride_and_base(11, 3, 8), Some(11));
+                assert_eq!($align_up_with_stride_and_base(12, 3, 8), Some(19));
+                // overflow
+                assert_eq!($align_up_with_stride_and_base(<$u>::MAX, 0, 2), None);
+                assert_eq!(
+                    $align_up_with_stride_and_base(<$u>::MAX, 1, 2),
+                    Some(<$u>::MAX)
+                );
+                assert_eq!($align_up_with_stride_and_base(<$u>::MAX, 2, 2), None);
+            }
+        };
+    }
+
+    test_align_up_with_stride_and_base_impl!(
+        u8,
+        u8_align_up_with_stride_and_base,
+        test_u8_align_up_with_stride_and_base
+    );
+    test_align_up_with_stride_and_base_impl!(
+        u16,
+        u16_align_up_with_stride_and_base,
+        test_u16_align_up_with_stride_and_base
+    );
+    test_align_up_with_stride_and_base_impl!(
+        u32,
+        u32_align_up_with_stride_and_base,
+        test_u32_align_up_with_stride_and_base
+    );
+    test_align_up_with_stride_and_base_impl!(
+        u64,
+        u64_align_up_with_stride_and_base,
+        test_u64_align_up_with_stride_and_base
+    );
+    test_align_up_with_stride_and_base_impl!(
+        u128,
+        u128_align_up_with_stride_and_base,
+        test_u128_align_up_with_stride_and_base
+    );
+    test_align_up_with_stride_and_base_impl!(
+        usize,
+        usize_align_up_with_stride_and_base,
+        test_usize_align_up_with_stride_and_base
+    );
+
+    macro_rules! const_checks_impl {
+        ($u:ty, $align_down:ident, $align_up:ident, $checked_align_up:ident, $is_aligned_to:ident) => {
+            const _: () = {
+                const MAX: $u = <$u>::MAX;
+                const TOP: $u = 1 << (<$u>::BITS - 1);
+                // align 1 is the identity
+                assert!($align_down(0, 1) == 0);
+                assert!($align_down(MAX, 1) == MAX);
+                assert!($align_up(0, 1) == 0);
+                assert!($align_up(MAX, 1) == MAX);
+                assert!($is_aligned_to(MAX, 1));
+                // address 0 is aligned to everything
+                assert!($align_down(0, TOP) == 0);
+                assert!($align_up(0, TOP) == 0);
+                assert!($is_aligned_to(0, TOP));
+                // largest alignment
+                assert!($align_down(MAX, TOP) == TOP);
+                assert!($align_down(TOP - 1, TOP) == 0);
+                assert!($align_up(1, TOP) == TOP);
+                assert!($align_up(TOP, TOP) == TOP);
+                assert!($is_aligned_to(TOP, TOP));
+                assert!(!$is_aligned_to(TOP - 1, TOP));
+                assert!($checked_align_up(TOP + 1, TOP).is_none());
+                // upper boundary
+                assert!($align_down(MAX, 2) == MAX - 1);
+                assert!($align_up(MAX - 1, 2) == MAX - 1);
+                assert!(!$is_aligned_to(MAX, 2));
+                assert!($checked_align_up(MAX, 2).is_none());
+                assert!(match $checked_align_up(MAX - 1, 2) {
+                    Some(x) => x == MAX - 1,
+                    None => false,
+                });
+                // idempotence
+                assert!($align_down($align_down(123, 8), 8) == $align_down(123, 8));
+                assert!($align_up($align_up(123, 8), 8) == $align_up(123, 8));
+                // ordering
+                assert!($align_down(123, 8) <= 123);
+                assert!($align_up(123, 8) >= 123);
+                assert!($align_up(123, 8) - $align_down(123, 8) == 8);
+            };
+        };
+    }
+
+    const_checks_impl!(
+        u8,
+        u8_align_down,
+        u8_align_up,
+        u8_checked_align_up,
+        u8_is_aligned_to
+    );
+    const_checks_impl!(
+        u16,
+        u16_align_down,
+        u16_align_up,
+        u16_checked_align_up,
+        u16_is_aligned_to
+    );
+    const_checks_impl!(
+        u32,
+        u32_align_down,
+        u32_align_up,
+        u32_checked_align_up,
+        u32_is_aligned_to
+    );
+    const_checks_impl!(
+        u64,
+        u64_align_down,
+        u64_align_up,
+        u64_checked_align_up,
+        u64_is_aligned_to
+    );
+    const_checks_impl!(
+        u128,
+        u128_align_down,
+        u128_align_up,
+        u128_checked_align_up,
+        u128_is_aligned_to
+    );
+    const_checks_impl!(
+        usize,
+        usize_align_down,
+        usize_align_up,
+        usize_checked_align_up,
+        usize_is_aligned_to
+    );
+
+    macro_rules! test_align_up_or_max_impl {
+        ($u:ty, $test_align_up_or_max:ident) => {
+            #[test]
+            fn $test_align_up_or_max() {
+                fn reserve<T: Align>(size: T, align: T) -> T {
+                    size.align_up_or_max(align)
+                }
+
+                assert_eq!(reserve::<$u>(123, 2), 124);
+                assert_eq!(reserve::<$u>(<$u>::MAX - 1, 2), <$u>::MAX - 1);
+                assert_eq!(reserve::<$u>(<$u>::MAX, 2), <$u>::MAX);
+                assert_eq!(
+                    reserve(Wrapping(<$u>::MAX), Wrapping(2)),
+                    Wrapping(<$u>::MAX)
+                );
+                assert_eq!(
+                    reserve(Saturating(<$u>::MAX), Saturating(2)),
+                    Saturating(<$u>::MAX)
+                );
+            }
+        };
+    }
+
+    test_align_up_or_max_impl!(u8, test_u8_align_up_or_max);
+    test_align_up_or_max_impl!(u16, test_u16_align_up_or_max);
+    test_align_up_or_max_impl!(u32, test_u32_align_up_or_max);
+    test_align_up_or_max_impl!(u64, test_u64_align_up_or_max);
+    test_align_up_or_max_impl!(u128, test_u128_align_up_or_max);
+    test_align_up_or_max_impl!(usize, test_usize_align_up_or_max);
+
+    #[test]
+    fn test_align_widen() {
+        assert_eq!(u16_align_down_u8(0x1234, 0x80), 0x1200);
+        assert_eq!(u16_align_up_u8(0x1234, 0x80), 0x1280);
+        assert_eq!(u64_align_down_u32(0x1_0000_1234, 0x1000), 0x1_0000_1000);
+        assert_eq!(u64_align_up_u32(0x1_0000_1234, 0x1000), 0x1_0000_2000);
+        assert_eq!(u128_align_up_u64(u128::MAX - 1, 2), u128::MAX - 1);
+        assert_eq!(usize_align_down_u16(0x1234, 0x1000), 0x1000);
+    }
+
+    #[test]
+    fn test_bits() {
+        use bits::*;
+
+        assert_eq!(align_down_bits(13, 8), 8);
+        assert_eq!(align_up_bits(13, 8), 16);
+        assert_eq!(align_up_bits(16, 8), 16);
+        assert!(is_aligned_bits(16, 8));
+        assert!(!is_aligned_bits(13, 8));
+    }
+
+    macro_rules! test_natural_align_impl {
+        ($u:ty, $test_natural_align:ident) => {
+            #[test]
+            fn $test_natural_align() {
+                fn align_field<T: Align>(offset: T) -> T {
+                    offset.align_up(T::NATURAL_ALIGN)
+                }
+
+                assert_eq!(<$u>::NATURAL_ALIGN as usize, mem::align_of::<$u>());
+                assert!(<$u>::NATURAL_ALIGN.is_power_of_two());
+                assert_eq!(align_field::<$u>(1), <$u>::NATURAL_ALIGN);
+                assert_eq!(Wrapping::<$u>::NATURAL_ALIGN, Wrapping(<$u>::NATURAL_ALIGN));
+                assert_eq!(
+                    Saturating::<$u>::NATURAL_ALIGN,
+                    Saturating(<$u>::NATURAL_ALIGN)
+                );
+            }
+        };
+    }
+
+    test_natural_align_impl!(u8, test_u8_natural_align);
+    test_natural_align_impl!(u16, test_u16_natural_align);
+    test_natural_align_impl!(u32, test_u32_natural_align);
+    test_natural_align_impl!(u64, test_u64_natural_align);
+    test_natural_align_impl!(u128, test_u128_natural_align);
+    test_natural_align_impl!(usize, test_usize_natural_align);
+
+    #[test]
+    fn test_is_aligned_to_type_align() {
+        assert!(is_aligned_to_type_align::<u8>(1));
+        assert!(is_aligned_to_type_align::<u32>(0x1000));
+        assert!(!is_aligned_to_type_align::<u32>(0x1001));
+        assert!(is_aligned_to_type_align::<()>(0x1001));
+
+        let words = [0_u32; 2];
+        let ptr = words.as_ptr().cast::<u8>();
+        assert!(is_ptr_aligned_for::<u32>(ptr));
+        assert!(!is_ptr_aligned_for::<u32>(ptr.wrapping_add(1)));
+        assert!(is_ptr_aligned_for::<u8>(ptr.wrapping_add(1)));
+    }
+
+    #[test]
+    fn test_align_pointer_to() {
+        let mut words = [0_u64; 4];
+        let ptr = words.as_mut_ptr().cast::<u8>();
+        assert_eq!(align_down_pointer_to::<u64>(ptr, 8), ptr.cast());
+        assert_eq!(
+            align_down_pointer_to::<u64>(ptr.wrapping_add(13), 8),
+            ptr.wrapping_add(8).cast()
+        );
+        assert_eq!(align_up_pointer_to::<u64>(ptr, 8), ptr.cast());
+        assert_eq!(
+            align_up_pointer_to::<u64>(ptr.wrapping_add(1), 8),
+            ptr.wrapping_add(8).cast()
+        );
+        assert_eq!(
+            align_up_pointer_to::<u8>(ptr.wrapping_add(9), 1),
+            ptr.wrapping_add(9)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "`align` must not be smaller than the alignment of `T`")]
+    fn test_align_pointer_to_small_align() {
+        let mut words = [0_u64; 1];
+        align_up_pointer_to::<u64>(words.as_mut_ptr().cast(), 1);
+    }
+
+    macro_rules! test_align_units_impl {
+        ($u:ty, $align_down_in_units:ident, $align_up_in_units:ident, $test_align_units:ident) => {
+            #[test]
+            fn $test_align_units() {
+                assert_eq!($align_down_in_units(0, 8), 0);
+                assert_eq!($align_down_in_units(23, 8), 2);
+                assert_eq!($align_down_in_units(24, 8), 3);
+                assert_eq!($align_down_in_units(<$u>::MAX, 1), <$u>::MAX);
+                assert_eq!($align_up_in_units(0, 8), 0);
+                assert_eq!($align_up_in_units(23, 8), 3);
+                assert_eq!($align_up_in_units(24, 8), 3);
+                assert_eq!($align_up_in_units(<$u>::MAX, 1), <$u>::MAX);
+                assert_eq!($align_up_in_units(<$u>::MAX, 2), <$u>::MAX / 2 + 1);
+            }
+        };
+    }
+
+    test_align_units_impl!(
+        u8,
+        u8_align_down_in_units,
+        u8_align_up_in_units,
+        test_u8_align_units
+    );
+    test_align_units_impl!(
+        u16,
+        u16_align_down_in_units,
+        u16_align_up_in_units,
+        test_u16_align_units
+    );
+    test_align_units_impl!(
+        u32,
+        u32_align_down_in_units,
+        u32_align_up_in_units,
+        test_u32_align_units
+    );
+    test_align_units_impl!(
+        u64,
+        u64_align_down_in_units,
+        u64_align_up_in_units,
+        test_u64_align_units
+    );
+    test_align_units_impl!(
+        u128,
+        u128_align_down_in_units,
+        u128_align_up_in_units,
+        test_u128_align_units
+    );
+    test_align_units_impl!(
+        usize,
+        usize_align_down_in_units,
+        usize_align_up_in_units,
+        test_usize_align_units
+    );
+
+    #[test]
+    fn test_align_units_blocks() {
+        assert_eq!(u64_align_up_in_units(1500, 512), 3);
+        assert_eq!(u64_align_down_in_units(1500, 512), 2);
+    }
+
+    #[cfg(any(feature = "always-validate", debug_assertions))]
+    #[test]
+    #[should_panic(expected = "`align` must be a power of two")]
+    fn test_align_not_power_of_two() {
+        u64_align_up(123, 3);
+    }
+
+    macro_rules! test_align_up_no_overflow_debug_impl {
+        ($u:ty, $align_up_no_overflow_debug:ident, $test_align_up_no_overflow_debug:ident) => {
+            #[test]
+            fn $test_align_up_no_overflow_debug() {
+                assert_eq!($align_up_no_overflow_debug(123, 2), 124);
+                assert_eq!($align_up_no_overflow_debug(<$u>::MAX, 1), <$u>::MAX);
+                #[cfg(not(debug_assertions))]
+                assert_eq!($align_up_no_overflow_debug(<$u>::MAX, 2), <$u>::MAX);
+            }
+        };
+    }
+
+    test_align_up_no_overflow_debug_impl!(
+        u8,
+        u8_align_up_no_overflow_debug,
+        test_u8_align_up_no_overflow_debug
+    );
+    test_align_up_no_overflow_debug_impl!(
+        u16,
+        u16_align_up_no_overflow_debug,
+        test_u16_align_up_no_overflow_debug
+    );
+    test_align_up_no_overflow_debug_impl!(
+        u32,
+        u32_align_up_no_overflow_debug,
+        test_u32_align_up_no_overflow_debug
+    );
+    test_align_up_no_overflow_debug_impl!(
+        u64,
+        u64_align_up_no_overflow_debug,
+        test_u64_align_up_no_overflow_debug
+    );
+    test_align_up_no_overflow_debug_impl!(
+        u128,
+        u128_align_up_no_overflow_debug,
+        test_u128_align_up_no_overflow_debug
+    );
+    test_align_up_no_overflow_debug_impl!(
+        usize,
+        usize_align_up_no_overflow_debug,
+        test_usize_align_up_no_overflow_debug
+    );
+
+    macro_rules! test_align_up_no_overflow_debug_overflow_impl {
+        ($u:ty, $align_up_no_overflow_debug:ident, $test_align_up_no_overflow_debug_overflow:ident) => {
+            #[cfg(debug_assertions)]
+            #[test]
+            #[should_panic]
+            fn $test_align_up_no_overflow_debug_overflow() {
+                $align_up_no_overflow_debug(<$u>::MAX, 2);
+            }
+        };
+    }
+
+    test_align_up_no_overflow_debug_overflow_impl!(
+        u8,
+        u8_align_up_no_overflow_debug,
+        test_u8_align_up_no_overflow_debug_overflow
+    );
+    test_align_up_no_overflow_debug_overflow_impl!(
+        u16,
+        u16_align_up_no_overflow_debug,
+        test_u16_align_up_no_overflow_debug_overflow
+    );
+    test_align_up_no_overflow_debug_overflow_impl!(
+        u32,
+        u32_align_up_no_overflow_debug,
+        test_u32_align_up_no_overflow_debug_overflow
+    );
+    test_align_up_no_overflow_debug_overflow_impl!(
+        u64,
+        u64_align_up_no_overflow_debug,
+        test_u64_align_up_no_overflow_debug_overflow
+    );
+    test_align_up_no_overflow_debug_overflow_impl!(
+        u128,
+        u128_align_up_no_overflow_debug,
+        test_u128_align_up_no_overflow_debug_overflow
+    );
+    test_align_up_no_overflow_debug_overflow_impl!(
+        usize,
+        usize_align_up_no_overflow_debug,
+        test_usize_align_up_no_overflow_debug_overflow
+    );
+
+    macro_rules! test_offset_to_aligned_impl {
+        ($u:ty, $offset_to_aligned:ident, $test_offset_to_aligned:ident) => {
+            #[test]
+            fn $test_offset_to_aligned() {
+                assert_eq!($offset_to_aligned(0, 1), 0);
+                assert_eq!($offset_to_aligned(<$u>::MAX, 1), 0);
+                assert_eq!($offset_to_aligned(123, 2), 1);
+                assert_eq!($offset_to_aligned(124, 4), 0);
+                assert_eq!($offset_to_aligned(125, 4), 3);
+                // no overflow at the upper boundary
+                assert_eq!($offset_to_aligned(<$u>::MAX, 2), 1);
+                assert_eq!($offset_to_aligned(<$u>::MAX, 1 << (<$u>::BITS - 1)), 1);
+                // trait and wrappers
+                assert_eq!((125 as $u).offset_to_aligned(4), 3);
+                assert_eq!(
+                    Wrapping(<$u>::MAX).offset_to_aligned(Wrapping(2)),
+                    Wrapping(1)
+                );
+                assert_eq!(
+                    Saturating(<$u>::MAX).offset_to_aligned(Saturating(2)),
+                    Saturating(1)
+                );
+            }
+        };
+    }
+
+    test_offset_to_aligned_impl!(u8, u8_offset_to_aligned, test_u8_offset_to_aligned);
+    test_offset_to_aligned_impl!(u16, u16_offset_to_aligned, test_u16_offset_to_aligned);
+    test_offset_to_aligned_impl!(u32, u32_offset_to_aligned, test_u32_offset_to_aligned);
+    test_offset_to_aligned_impl!(u64, u64_offset_to_aligned, test_u64_offset_to_aligned);
+    test_offset_to_aligned_impl!(u128, u128_offset_to_aligned, test_u128_offset_to_aligned);
+    test_offset_to_aligned_impl!(usize, usize_offset_to_aligned, test_usize_offset_to_aligned);
+
+    macro_rules! test_align_region_subtract_impl {
+        ($u:ty, $align_region_subtract:ident, $test_align_region_subtract:ident) => {
+            #[test]
+            fn $test_align_region_subtract() {
+                // sub-region in the middle
+                assert_eq!(
+                    $align_region_subtract(0x10, 0x40, 0x20, 0x10),
+                    [(0x10, 0x10), (0x30, 0x20)]
+                );
+                // sub-region at the start
+                assert_eq!(
+                    $align_region_subtract(0x10, 0x40, 0x10, 0x10),
+                    [(0x10, 0), (0x20, 0x30)]
+                );
+                // sub-region at the end
+                assert_eq!(
+                    $align_region_subtract(0x10, 0x40, 0x40, 0x10),
+                    [(0x10, 0x30), (0x50, 0)]
+                );
+                // sub-region covers the region
+                assert_eq!(
+                    $align_region_subtract(0x10, 0x40, 0, 0x60),
+                    [(0x10, 0), (0x50, 0)]
+                );
+                // disjoint sub-regions
+                assert_eq!(
+                    $align_region_subtract(0x10, 0x40, 0, 0x10),
+                    [(0x10, 0), (0x10, 0x40)]
+                );
+                assert_eq!(
+                    $align_region_subtract(0x10, 0x40, 0x60, 0x10),
+                    [(0x10, 0x40), (0x50, 0)]
+                );
+                // upper boundary
+                assert_eq!(
+                    $align_region_subtract(0, <$u>::MAX, 0x10, <$u>::MAX),
+                    [(0, 0x10), (<$u>::MAX, 0)]
+                );
+            }
+        };
+    }
+
+    test_align_region_subtract_impl!(u8, u8_align_region_subtract, test_u8_align_region_subtract);
+    test_align_region_subtract_impl!(
+        u16,
+        u16_align_region_subtract,
+        test_u16_align_region_subtract
+    );
+    test_align_region_subtract_impl!(
+        u32,
+        u32_align_region_subtract,
+        test_u32_align_region_subtract
+    );
+    test_align_region_subtract_impl!(
+        u64,
+        u64_align_region_subtract,
+        test_u64_align_region_subtract
+    );
+    test_align_region_subtract_impl!(
+        u128,
+        u128_align_region_subtract,
+        test_u128_align_region_subtract
+    );
+    test_align_region_subtract_impl!(
+        usize,
+        usize_align_region_subtract,
+        test_usize_align_region_subtract
+    );
+
+    macro_rules! test_align_up_or_overflow_impl {
+        ($u:ty, $align_up_or_overflow:ident, $test_align_up_or_overflow:ident) => {
+            #[test]
+            fn $test_align_up_or_overflow() {
+                assert_eq!($align_up_or_overflow(123, 2), Ok(124));
+                assert_eq!($align_up_or_overflow(<$u>::MAX, 1), Ok(<$u>::MAX));
+                assert_eq!($align_up_or_overflow(<$u>::MAX, 2), Err(0));
+            }
+        };
+    }
+
+    test_align_up_or_overflow_impl!(u8, u8_align_up_or_overflow, test_u8_align_up_or_overflow);
+    test_align_up_or_overflow_impl!(u16, u16_align_up_or_overflow, test_u16_align_up_or_overflow);
+    test_align_up_or_overflow_impl!(u32, u32_align_up_or_overflow, test_u32_align_up_or_overflow);
+    test_align_up_or_overflow_impl!(u64, u64_align_up_or_overflow, test_u64_align_up_or_overflow);
+    test_align_up_or_overflow_impl!(
+        u128,
+        u128_align_up_or_overflow,
+        test_u128_align_up_or_overflow
+    );
+    test_align_up_or_overflow_impl!(
+        usize,
+        usize_align_up_or_overflow,
+        test_usize_align_up_or_overflow
+    );
+
+    #[test]
+    fn test_cache() {
+        use cache::*;
+
+        assert_eq!(align_up_to_next_cache_set(0, 64, 64), 0);
+        assert_eq!(align_up_to_next_cache_set(1, 64, 64), 0x1000);
+        assert_eq!(align_up_to_next_cache_set(0x1000, 64, 64), 0x1000);
+        assert_eq!(cache_set_index(0, 64, 64), 0);
+        assert_eq!(cache_set_index(0x40, 64, 64), 1);
+        assert_eq!(cache_set_index(0xfff, 64, 64), 63);
+        assert_eq!(cache_set_index(0x1000, 64, 64), 0);
+
+        let layout = align_to_cache_aligned_struct::<u32>(3);
+        assert_eq!((layout.size(), layout.align()), (64, 64));
+        let layout = align_to_cache_aligned_struct::<u64>(9);
+        assert_eq!((layout.size(), layout.align()), (128, 64));
+        let layout = align_to_cache_aligned_struct::<u8>(0);
+        assert_eq!((layout.size(), layout.align()), (0, 64));
+
+        #[repr(align(128))]
+        struct Padded;
+        let layout = align_to_cache_aligned_struct::<Padded>(2);
+        assert_eq!((layout.size(), layout.align()), (0, 128));
+    }
+
+    macro_rules! test_align_check_invariants_impl {
+        ($u:ty, $test_align_check_invariants:ident) => {
+            #[cfg(debug_assertions)]
+            #[test]
+            fn $test_align_check_invariants() {
+                for align in [1, 2, 4, 1 << (<$u>::BITS - 1)] {
+                    for addr in [0, 1, 2, 3, 123] {
+                        align_check_invariants::<$u>(addr, align);
+                        align_check_invariants(Wrapping::<$u>(addr), Wrapping(align));
+                        align_check_invariants(Saturating::<$u>(addr), Saturating(align));
+                    }
+                }
+                align_check_invariants::<$u>(<$u>::MAX - 1, 2);
+            }
+        };
+    }
+
+    #[test]
+    fn test_align_wrapper_overflow() {
+        assert_eq!(Wrapping(u8::MAX).align_up(Wrapping(2)), Wrapping(0));
+        assert_eq!(Wrapping(u8::MAX).align_to_nearest(Wrapping(2)), Wrapping(0));
+        assert_eq!(
+            Saturating(u8::MAX).align_up(Saturating(2)),
+            Saturating(u8::MAX)
+        );
+        assert_eq!(
+            Saturating(u8::MAX).align_to_nearest(Saturating(2)),
+            Saturating(u8::MAX)
+        );
+        assert!(!Saturating(u8::MAX)
+            .align_up(Saturating(2))
+            .is_aligned_to(Saturating(2)));
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "0 = align_up(255, 2) < 255")]
+    fn test_align_check_invariants_wrapping_overflow() {
+        align_check_invariants(Wrapping(u8::MAX), Wrapping(2));
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "255 = align_up(255, 2) is not aligned")]
+    fn test_align_check_invariants_saturating_overflow() {
+        align_check_invariants(Saturating(u8::MAX), Saturating(2));
+    }
+
+    test_align_check_invariants_impl!(u8, test_u8_align_check_invariants);
+    test_align_check_invariants_impl!(u16, test_u16_align_check_invariants);
+    test_align_check_invariants_impl!(u32, test_u32_align_check_invariants);
+    test_align_check_invariants_impl!(u64, test_u64_align_check_invariants);
+    test_align_check_invariants_impl!(u128, test_u128_align_check_invariants);
+    test_align_check_invariants_impl!(usize, test_usize_align_check_invariants);
+
+    #[test]
+    fn test_const_align_macros() {
+        const SIZE: usize = 1500;
+
+        let up: [u8; const_align_up!(SIZE, 64)] = [0; 1536];
+        let down: [u8; const_align_down!(SIZE, 64)] = [0; 1472];
+        assert_eq!(up.len(), 1536);
+        assert_eq!(down.len(), 1472);
+        assert_eq!(const_align_up!(123_u8, 2), 124);
+        assert_eq!(const_align_up!(124_u32, 4), 124);
+        assert_eq!(const_align_down!(123_u64, 2), 122);
+        assert_eq!(const_align_down!(0_u128, 1 << 127), 0);
+    }
+
+    macro_rules! test_align_sub_impl {
+        ($u:ty, $align_saturating_sub:ident, $align_checked_sub:ident, $test_align_sub:ident) => {
+            #[test]
+            fn $test_align_sub() {
+                assert_eq!($align_saturating_sub(123, 8), 112);
+                assert_eq!($align_saturating_sub(8, 8), 0);
+                assert_eq!($align_saturating_sub(7, 8), 0);
+                assert_eq!($align_saturating_sub(<$u>::MAX, 2), <$u>::MAX - 3);
+                assert_eq!($align_checked_sub(123, 8), Some(112));
+                assert_eq!($align_checked_sub(8, 8), Some(0));
+                assert_eq!($align_checked_sub(7, 8), None);
+                assert_eq!($align_checked_sub(0, 1), None);
+            }
+        };
+    }
+
+    test_align_sub_impl!(
+        u8,
+        u8_align_saturating_sub,
+        u8_align_checked_sub,
+        test_u8_align_sub
+    );
+    test_align_sub_impl!(
+        u16,
+        u16_align_saturating_sub,
+        u16_align_checked_sub,
+        test_u16_align_sub
+    );
+    test_align_sub_impl!(
+        u32,
+        u32_align_saturating_sub,
+        u32_align_checked_sub,
+        test_u32_align_sub
+    );
+    test_align_sub_impl!(
+        u64,
+        u64_align_saturating_sub,
+        u64_align_checked_sub,
+        test_u64_align_sub
+    );
+    test_align_sub_impl!(
+        u128,
+        u128_align_saturating_sub,
+        u128_align_checked_sub,
+        test_u128_align_sub
+    );
+    test_align_sub_impl!(
+        usize,
+        usize_align_saturating_sub,
+        usize_align_checked_sub,
+        test_usize_align_sub
+    );
+
+    macro_rules! test_align_pad_impl {
+        ($u:ty, $align_pad_to:ident, $align_pad_bytes:ident, $test_align_pad:ident) => {
+            #[test]
+            fn $test_align_pad() {
+                assert_eq!($align_pad_to(0, 8), 0);
+                assert_eq!($align_pad_to(14, 4), 2);
+                assert_eq!($align_pad_to(<$u>::MAX, 2), 1);
+                #[cfg(feature = "alloc")]
+                {
+                    assert_eq!($align_pad_bytes(14, 4), [0, 0]);
+                    assert!($align_pad_bytes(16, 4).is_empty());
+                }
+            }
+        };
+    }
+
+    test_align_pad_impl!(u8, u8_align_pad_to, u8_align_pad_bytes, test_u8_align_pad);
+    test_align_pad_impl!(
+        u16,
+        u16_align_pad_to,
+        u16_align_pad_bytes,
+        test_u16_align_pad
+    );
+    test_align_pad_impl!(
+        u32,
+        u32_align_pad_to,
+        u32_align_pad_bytes,
+        test_u32_align_pad
+    );
+    test_align_pad_impl!(
+        u64,
+        u64_align_pad_to,
+        u64_align_pad_bytes,
+        test_u64_align_pad
+    );
+    test_align_pad_impl!(
+        u128,
+        u128_align_pad_to,
+        u128_align_pad_bytes,
+        test_u128_align_pad
+    );
+    test_align_pad_impl!(
+        usize,
+        usize_align_pad_to,
+        usize_align_pad_bytes,
+        test_usize_align_pad
+    );
+
+    macro_rules! test_align_regions_impl {
+        ($u:ty, $align_regions_up:ident, $align_regions_down:ident, $test_align_regions:ident) => {
+            #[test]
+            fn $test_align_regions() {
+                assert_eq!($align_regions_up([], 0x10), Ok([]));
+                assert_eq!(
+                    $align_regions_up(
+                        [(0x10, 0x20), (0x18, 0x20), (0x31, 0x08), (0x31, 0x10)],
+                        0x10
+                    ),
+                    Ok([(0x10, 0x20), (0x20, 0x18), (0x40, 0), (0x40, 0x01)])
+                );
+                assert_eq!($align_regions_up([(0, 0x10), (<$u>::MAX, 0)], 0x10), Err(1));
+                assert_eq!(
+                    $align_regions_up([(0, 0x10), (0x10, <$u>::MAX)], 0x10),
+                    Err(1)
+                );
+                assert_eq!(
+                    $align_regions_down(
+                        [(0x10, 0x20), (0x18, 0x20), (0x31, 0x08), (0x21, 0x10)],
+                        0x10
+                    ),
+                    Ok([(0x10, 0x20), (0x18, 0x18), (0x31, 0), (0x21, 0x0f)])
+                );
+                assert_eq!(
+                    $align_regions_down([(0, 0x10), (0x10, <$u>::MAX)], 0x10),
+                    Err(1)
+                );
+            }
+        };
+    }
+
+    test_align_regions_impl!(
+        u8,
+        u8_align_regions_up,
+        u8_align_regions_down,
+        test_u8_align_regions
+    );
+    test_align_regions_impl!(
+        u16,
+        u16_align_regions_up,
+        u16_align_regions_down,
+        test_u16_align_regions
+    );
+    test_align_regions_impl!(
+        u32,
+        u32_align_regions_up,
+        u32_align_regions_down,
+        test_u32_align_regions
+    );
+    test_align_regions_impl!(
+        u64,
+        u64_align_regions_up,
+        u64_align_regions_down,
+        test_u64_align_regions
+    );
+    test_align_regions_impl!(
+        u128,
+        u128_align_regions_up,
+        u128_align_regions_down,
+        test_u128_align_regions
+    );
+    test_align_regions_impl!(
+        usize,
+        usize_align_regions_up,
+        usize_align_regions_down,
+        test_usize_align_regions
+    );
+
+    macro_rules! test_align_sequence_impl {
+        ($u:ty, $align_sequence_start:ident, $align_sequence_end:ident, $test_align_sequence:ident) => {
+            #[test]
+            fn $test_align_sequence() {
+                assert_eq!($align_sequence_start(0, 16), Some(0));
+                assert_eq!($align_sequence_start(3, 16), Some(48));
+                assert_eq!($align_sequence_start(<$u>::MAX, 1), Some(<$u>::MAX));
+                assert_eq!($align_sequence_start(<$u>::MAX, 2), None);
+                assert_eq!($align_sequence_end(0, 16), Some(16));
+                assert_eq!($align_sequence_end(3, 16), Some(64));
+                assert_eq!($align_sequence_end(<$u>::MAX - 1, 1), Some(<$u>::MAX));
+                assert_eq!($align_sequence_end(<$u>::MAX, 1), None);
+            }
+        };
+    }
+
+    test_align_sequence_impl!(
+        u8,
+        u8_align_sequence_start,
+        u8_align_sequence_end,
+        test_u8_align_sequence
+    );
+    test_align_sequence_impl!(
+        u16,
+        u16_align_sequence_start,
+        u16_align_sequence_end,
+        test_u16_align_sequence
+    );
+    test_align_sequence_impl!(
+        u32,
+        u32_align_sequence_start,
+        u32_align_sequence_end,
+        test_u32_align_sequence
+    );
+    test_align_sequence_impl!(
+        u64,
+        u64_align_sequence_start,
+        u64_align_sequence_end,
+        test_u64_align_sequence
+    );
+    test_align_sequence_impl!(
+        u128,
+        u128_align_sequence_start,
+        u128_align_sequence_end,
+        test_u128_align_sequence
+    );
+    test_align_sequence_impl!(
+        usize,
+        usize_align_sequence_start,
+        usize_align_sequence_end,
+        test_usize_align_sequence
+    );
+
+    macro_rules! test_align_up_nonzero_result_impl {
+        ($u:ty, $align_up_nonzero_result:ident, $test_align_up_nonzero_result:ident) => {
+            #[test]
+            fn $test_align_up_nonzero_result() {
+                assert_eq!($align_up_nonzero_result(0, 1), None);
+                assert_eq!($align_up_nonzero_result(0, 16), None);
+                assert_eq!($align_up_nonzero_result(1, 16), Some(16));
+                assert_eq!($align_up_nonzero_result(<$u>::MAX, 1), Some(<$u>::MAX));
+                assert_eq!($align_up_nonzero_result(<$u>::MAX, 2), None);
+            }
+        };
+    }
+
+    test_align_up_nonzero_result_impl!(
+        u8,
+        u8_align_up_nonzero_result,
+        test_u8_align_up_nonzero_result
+    );
+    test_align_up_nonzero_result_impl!(
+        u16,
+        u16_align_up_nonzero_result,
+        test_u16_align_up_nonzero_result
+    );
+    test_align_up_nonzero_result_impl!(
+        u32,
+        u32_align_up_nonzero_result,
+        test_u32_align_up_nonzero_result
+    );
+    test_align_up_nonzero_result_impl!(
+        u64,
+        u64_align_up_nonzero_result,
+        test_u64_align_up_nonzero_result
+    );
+    test_align_up_nonzero_result_impl!(
+        u128,
+        u128_align_up_nonzero_result,
+        test_u128_align_up_nonzero_result
+    );
+    test_align_up_nonzero_result_impl!(
+        usize,
+        usize_align_up_nonzero_result,
+        test_usize_align_up_nonzero_result
+    );
+
+    #[cfg(feature = "crypto")]
+    #[test]
+    fn test_crypto() {
+        use crypto::*;
+
+        assert_eq!(align_down_32(33), 32);
+        assert_eq!(align_up_32(33), 64);
+        assert!(is_32_aligned(64));
+        assert!(!is_32_aligned(33));
+        assert_eq!(align_down_64(127), 64);
+        assert_eq!(align_up_64(65), 128);
+        assert!(is_64_aligned(128));
+        assert!(!is_64_aligned(96));
+    }
+
+    macro_rules! test_align_round_trip_impl {
+        ($u:ty, $test_align_round_trip:ident) => {
+            #[test]
+            fn $test_align_round_trip() {
+                for shift in 0..<$u>::BITS {
+                    let align: $u = 1 << shift;
+                    for addr in [0, 1, align - 1, align, <$u>::MAX - align + 1] {
+                        align_round_trip_check(addr, align);
+                        align_round_trip_check(Wrapping(addr), Wrapping(align));
+                        align_round_trip_check(Saturating(addr), Saturating(align));
+                    }
+                }
+            }
+        };
+    }
+
+    test_align_round_trip_impl!(u8, test_u8_align_round_trip);
+    test_align_round_trip_impl!(u16, test_u16_align_round_trip);
+    test_align_round_trip_impl!(u32, test_u32_align_round_trip);
+    test_align_round_trip_impl!(u64, test_u64_align_round_trip);
+    test_align_round_trip_impl!(u128, test_u128_align_round_trip);
+    test_align_round_trip_impl!(usize, test_usize_align_round_trip);
+
+    macro_rules! test_align_down_to_min_one_impl {
+        ($u:ty, $align_down_to_min_one:ident, $test_align_down_to_min_one:ident) => {
+            #[test]
+            fn $test_align_down_to_min_one() {
+                assert_eq!($align_down_to_min_one(0, 4), 0);
+                assert_eq!($align_down_to_min_one(1, 4), 1);
+                assert_eq!($align_down_to_min_one(3, 4), 1);
+                assert_eq!($align_down_to_min_one(4, 4), 4);
+                assert_eq!($align_down_to_min_one(7, 4), 4);
+                assert_eq!($align_down_to_min_one(<$u>::MAX, 2), <$u>::MAX - 1);
+            }
+        };
+    }
+
+    test_align_down_to_min_one_impl!(u8, u8_align_down_to_min_one, test_u8_align_down_to_min_one);
+    test_align_down_to_min_one_impl!(
+        u16,
+        u16_align_down_to_min_one,
+        test_u16_align_down_to_min_one
+    );
+    test_align_down_to_min_one_impl!(
+        u32,
+        u32_align_down_to_min_one,
+        test_u32_align_down_to_min_one
+    );
+    test_align_down_to_min_one_impl!(
+        u64,
+        u64_align_down_to_min_one,
+        test_u64_align_down_to_min_one
+    );
+    test_align_down_to_min_one_impl!(
+        u128,
+        u128_align_down_to_min_one,
+        test_u128_align_down_to_min_one
+    );
+    test_align_down_to_min_one_impl!(
+        usize,
+        usize_align_down_to_min_one,
+        test_usize_align_down_to_min_one
+    );
+
+    #[cfg(feature = "isa-x86_64")]
+    #[test]
+    fn test_isa_x86_64() {
+        use isa::x86_64::*;
+
+        assert_eq!(PAGE_SIZE, 4096);
+        assert_eq!(align_down_to_page(0x1234), 0x1000);
+        assert_eq!(align_up_to_page(0x1234), 0x2000);
+        assert_eq!(align_down_to_large_page(0x20_1234), 0x20_0000);
+        assert_eq!(align_up_to_large_page(0x20_1234), 0x40_0000);
+        assert_eq!(align_down_to_huge_page(0x4000_1234), 0x4000_0000);
+        assert_eq!(align_up_to_huge_page(0x4000_1234), 0x8000_0000);
+    }
+
+    #[cfg(feature = "isa-aarch64")]
+    #[test]
+    fn test_isa_aarch64() {
+        use isa::aarch64::*;
+
+        assert_eq!(align_up_to_page(1), PAGE_SIZE);
+        assert_eq!(align_up_to_large_page(1), LARGE_PAGE_SIZE);
+        assert_eq!(align_up_to_huge_page(1), HUGE_PAGE_SIZE);
+    }
+
+    #[cfg(feature = "isa-riscv")]
+    #[test]
+    fn test_isa_riscv() {
+        use isa::riscv::*;
+
+        assert_eq!(align_up_to_page(1), PAGE_SIZE);
+        assert_eq!(align_up_to_large_page(1), LARGE_PAGE_SIZE);
+        assert_eq!(align_up_to_huge_page(1), HUGE_PAGE_SIZE);
+    }
+
+    #[test]
+    fn test_align_slice() {
+        #[repr(align(64))]
+        struct Lines([u32; 48]);
+
+        let lines = Lines([0; 48]);
+        let start = lines.0.as_ptr() as usize;
+        assert_eq!(align_down_slice_addr(&lines.0, 64), start);
+        assert_eq!(align_up_slice_end(&lines.0, 64), start + 192);
+        assert_eq!(align_down_slice_addr(&lines.0[1..], 64), start);
+        assert_eq!(align_up_slice_end(&lines.0[..1], 64), start + 64);
+        assert_eq!(align_up_slice_end(&lines.0[..0], 64), start);
+    }
+
+    #[test]
+    fn test_align_up_log2_table() {
+        for log2_align in 0..usize::BITS as usize {
+            let align = 1 << log2_align;
+            for addr in [0, 1, align - 1, align] {
+                assert_eq!(
+                    align_up_log2_table(addr, log2_align),
+                    usize_align_up(addr, align)
+                );
+            }
+        }
+        assert_eq!(align_up_log2_table(0x1234, 12), 0x2000);
+        assert_eq!(align_up_log2_table(usize::MAX, 0), usize::MAX);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_align_up_log2_table_out_of_range() {
+        align_up_log2_table(0, usize::BITS as usize);
+    }
+
+    #[test]
+    fn test_align_up_verify() {
+        assert_eq!(align_up_verify(13_u64, 4, u64_align_up), 16);
+        assert_eq!(
+            align_up_verify(13_usize, 4, |addr, align| (addr + align - 1) & !(align - 1)),
+            16
+        );
+        assert_eq!(
+            align_up_verify(Wrapping(13_u8), Wrapping(4), |addr, align| {
+                Wrapping(u8_align_up(addr.0, align.0))
+            }),
+            Wrapping(16)
+        );
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "reference implementation differs for align_up(13, 4)")]
+    fn test_align_up_verify_mismatch() {
+        align_up_verify(13_u32, 4, |addr, _align| addr);
+    }
+
+    #[test]
+    fn test_align_mod() {
+        assert_eq!(crate::u8::align_up(13, 4), u8_align_up(13, 4));
+        assert_eq!(crate::u64::align_down(13, 4), u64_align_down(13, 4));
+        {
+            use crate::usize::*;
+            assert_eq!(align_up(13, 4), 16);
+            assert_eq!(align_down(13, 4), 12);
+            assert!(is_aligned_to(16, 4));
+            assert_eq!(checked_align_up(usize::MAX, 2), None);
+        }
+    }
+
+    #[test]
+    fn test_align_segment_vaddr() {
+        assert!(segments_compatible_alignment(0x40_1000, 0x1000, 0x1000));
+        assert!(segments_compatible_alignment(0x40_1234, 0x2234, 0x1000));
+        assert!(segments_compatible_alignment(0x1000, 0x40_2000, 0x1000));
+        assert!(!segments_compatible_alignment(0x40_1000, 0x1234, 0x1000));
+
+        assert_eq!(align_segment_vaddr(0x40_1000, 0x1000, 0x1000), 0x40_1000);
+        assert_eq!(align_segment_vaddr(0x40_1000, 0x1234, 0x1000), 0x40_1234);
+        assert_eq!(align_segment_vaddr(0x40_1300, 0x1234, 0x1000), 0x40_2234);
+        assert_eq!(align_segment_vaddr(0x40_1000, 0x40_0fff, 0x1000), 0x40_1fff);
+        assert!(segments_compatible_alignment(
+            align_segment_vaddr(0x40_1300, 0x1234, 0x1000),
+            0x1234,
+            0x1000
+        ));
+    }
+
+    #[cfg(any(feature = "always-validate", debug_assertions))]
+    #[test]
+    #[should_panic(expected = "`size` must be a power of two")]
+    fn test_is_naturally_aligned_zero_size() {
+        u64_is_naturally_aligned(0x2000, 0);
+    }
+
+    #[test]
+    fn test_merge_adjacent_aligned_regions() {
+        let mut regions = [
+            (0x0000_u64, 0x1000),
+            (0x1000, 0x2000),
+            (0x3000, 0x5000),
+            (0x4000, 0x4800),
+            (0x4800, 0x6000),
+            (0x8000, 0x9000),
+        ];
+        let len = merge_adjacent_aligned_regions_fixed(&mut regions);
+        assert_eq!(
+            regions[..len],
+            [(0x0000, 0x2000), (0x3000, 0x6000), (0x8000, 0x9000)]
+        );
+
+        let mut empty: [(u64, u64); 0] = [];
+        assert_eq!(merge_adjacent_aligned_regions_fixed(&mut empty), 0);
+
+        #[cfg(feature = "alloc")]
+        {
+            let mut regions = alloc::vec![(0_u64, 0x1000), (0x1000, 0x2000), (0x3000, 0x4000)];
+            merge_adjacent_aligned_regions(&mut regions);
+            assert_eq!(regions, [(0, 0x2000), (0x3000, 0x4000)]);
+        }
+    }
+
+    macro_rules! test_alignment_order_impl {
+        ($u:ty, $alignment_order:ident, $test_alignment_order:ident) => {
+            #[test]
+            fn $test_alignment_order() {
+                assert_eq!($alignment_order(0), <$u>::BITS);
+                assert_eq!($alignment_order(1), 0);
+                assert_eq!($alignment_order(0x30), 4);
+                assert_eq!($alignment_order(<$u>::MAX), 0);
+                assert_eq!($alignment_order(1 << (<$u>::BITS - 1)), <$u>::BITS - 1);
+                // trait and wrappers
+                assert_eq!((0x30 as $u).alignment_order(), 4);
+                assert_eq!(Wrapping::<$u>(0x30).alignment_order(), 4);
+                assert_eq!(Saturating::<$u>(0x30).alignment_order(), 4);
+            }
+        };
+    }
+
+    test_alignment_order_impl!(u8, u8_alignment_order, test_u8_alignment_order);
+    test_alignment_order_impl!(u16, u16_alignment_order, test_u16_alignment_order);
+    test_alignment_order_impl!(u32, u32_alignment_order, test_u32_alignment_order);
+    test_alignment_order_impl!(u64, u64_alignment_order, test_u64_alignment_order);
+    test_alignment_order_impl!(u128, u128_alignment_order, test_u128_alignment_order);
+    test_alignment_order_impl!(usize, usize_alignment_order, test_usize_alignment_order);
+
+    #[test]
+    fn test_alignment_order_page() {
+        assert_eq!(u64_alignment_order(0x3000), 12);
+    }
+
+    macro_rules! test_align_clamp_impl {
+        ($u:ty, $align_down_clamp:ident, $align_up_clamp:ident, $test_align_clamp:ident) => {
+            #[test]
+            fn $test_align_clamp() {
+                assert_eq!($align_down_clamp(123, 8, 0), 120);
+                assert_eq!($align_down_clamp(123, 8, 120), 120);
+                assert_eq!($align_down_clamp(123, 8, 121), 121);
+                assert_eq!($align_up_clamp(123, 8, <$u>::MAX), 128);
+                assert_eq!($align_up_clamp(123, 8, 128), 128);
+                assert_eq!($align_up_clamp(123, 8, 127), 127);
+                // overflow
+                assert_eq!($align_up_clamp(<$u>::MAX, 2, <$u>::MAX), <$u>::MAX);
+                // trait and wrappers
+                assert_eq!((123 as $u).align_down_clamp(8, 121), 121);
+                assert_eq!((123 as $u).align_up_clamp(8, 127), 127);
+                assert_eq!(
+                    Wrapping(<$u>::MAX).align_up_clamp(Wrapping(2), Wrapping(<$u>::MAX)),
+                    Wrapping(<$u>::MAX)
+                );
+                assert_eq!(
+                    Saturating::<$u>(123).align_down_clamp(Saturating(8), Saturating(121)),
+                    Saturating(121)
+                );
+            }
+        };
+    }
+
+    test_align_clamp_impl!(
+        u8,
+        u8_align_down_clamp,
+        u8_align_up_clamp,
+        test_u8_align_clamp
+    );
+    test_align_clamp_impl!(
+        u16,
+        u16_align_down_clamp,
+        u16_align_up_clamp,
+        test_u16_align_clamp
+    );
+    test_align_clamp_impl!(
+        u32,
+        u32_align_down_clamp,
+        u32_align_up_clamp,
+        test_u32_align_clamp
+    );
+    test_align_clamp_impl!(
+        u64,
+        u64_align_down_clamp,
+        u64_align_up_clamp,
+        test_u64_align_clamp
+    );
+    test_align_clamp_impl!(
+        u128,
+        u128_align_down_clamp,
+        u128_align_up_clamp,
+        test_u128_align_clamp
+    );
+    test_align_clamp_impl!(
+        usize,
+        usize_align_down_clamp,
+        usize_align_up_clamp,
+        test_usize_align_clamp
+    );
+
+    macro_rules! test_align_up_difference_impl {
+        ($u:ty, $align_up_difference:ident, $test_align_up_difference:ident) => {
+            #[test]
+            fn $test_align_up_difference() {
+                assert_eq!($align_up_difference(0, 0, 8), Some(0));
+                assert_eq!($align_up_difference(1, 8, 8), Some(0));
+                assert_eq!($align_up_difference(1, 9, 8), Some(8));
+                assert_eq!($align_up_difference(8, 17, 8), Some(16));
+                assert_eq!($align_up_difference(3, 5, 1), Some(2));
+                assert_eq!($align_up_difference(9, 8, 8), None);
+                assert_eq!($align_up_difference(0, <$u>::MAX, 2), None);
+                assert_eq!(
+                    $align_up_difference(0, <$u>::MAX - 1, 2),
+                    Some(<$u>::MAX - 1)
+                );
+            }
+        };
+    }
+
+    test_align_up_difference_impl!(u8, u8_align_up_difference, test_u8_align_up_difference);
+    test_align_up_difference_impl!(u16, u16_align_up_difference, test_u16_align_up_difference);
+    test_align_up_difference_impl!(u32, u32_align_up_difference, test_u32_align_up_difference);
+    test_align_up_difference_impl!(u64, u64_align_up_difference, test_u64_align_up_difference);
+    test_align_up_difference_impl!(
+        u128,
+        u128_align_up_difference,
+        test_u128_align_up_difference
+    );
+    test_align_up_difference_impl!(
+        usize,
+        usize_align_up_difference,
+        test_usize_align_up_difference
+    );
+
+    macro_rules! test_align_up_for_dma_impl {
+        ($u:ty, $align_up_for_dma:ident, $test_align_up_for_dma:ident) => {
+            #[test]
+            fn $test_align_up_for_dma() {
+                assert_eq!($align_up_for_dma(0, 4, 64), 0);
+                assert_eq!($align_up_for_dma(1, 4, 64), 64);
+                assert_eq!($align_up_for_dma(65, 64, 4), 128);
+                assert_eq!($align_up_for_dma(3, 8, 8), 8);
+                assert_eq!($align_up_for_dma(<$u>::MAX, 1, 1), <$u>::MAX);
+            }
+        };
+    }
+
+    test_align_up_for_dma_impl!(u8, u8_align_up_for_dma, test_u8_align_up_for_dma);
+    test_align_up_for_dma_impl!(u16, u16_align_up_for_dma, test_u16_align_up_for_dma);
+    test_align_up_for_dma_impl!(u32, u32_align_up_for_dma, test_u32_align_up_for_dma);
+    test_align_up_for_dma_impl!(u64, u64_align_up_for_dma, test_u64_align_up_for_dma);
+    test_align_up_for_dma_impl!(u128, u128_align_up_for_dma, test_u128_align_up_for_dma);
+    test_align_up_for_dma_impl!(usize, usize_align_up_for_dma, test_usize_align_up_for_dma);
+
+    macro_rules! test_align_down_to_containing_block_impl {
+        ($u:ty, $align_down_to_containing_block:ident, $test_align_down_to_containing_block:ident) => {
+            #[test]
+            fn $test_align_down_to_containing_block() {
+                assert_eq!($align_down_to_containing_block(0, 16), Some((0, 16)));
+                assert_eq!($align_down_to_containing_block(15, 16), Some((0, 16)));
+                assert_eq!($align_down_to_containing_block(16, 16), Some((16, 32)));
+                assert_eq!($align_down_to_containing_block(7, 1), Some((7, 8)));
+                assert_eq!($align_down_to_containing_block(<$u>::MAX, 1), None);
+                assert_eq!($align_down_to_containing_block(<$u>::MAX, 16), None);
+                assert_eq!(
+                    $align_down_to_containing_block(<$u>::MAX - 16, 16),
+                    Some((<$u>::MAX - 31, <$u>::MAX - 15))
+                );
+            }
+        };
+    }
+
+    test_align_down_to_containing_block_impl!(
+        u8,
+        u8_align_down_to_containing_block,
+        test_u8_align_down_to_containing_block
+    );
+    test_align_down_to_containing_block_impl!(
+        u16,
+        u16_align_down_to_containing_block,
+        test_u16_align_down_to_containing_block
+    );
+    test_align_down_to_containing_block_impl!(
+        u32,
+        u32_align_down_to_containing_block,
+        test_u32_align_down_to_containing_block
+    );
+    test_align_down_to_containing_block_impl!(
+        u64,
+        u64_align_down_to_containing_block,
+        test_u64_align_down_to_containing_block
+    );
+    test_align_down_to_containing_block_impl!(
+        u128,
+        u128_align_down_to_containing_block,
+        test_u128_align_down_to_containing_block
+    );
+    test_align_down_to_containing_block_impl!(
+        usize,
+        usize_align_down_to_containing_block,
+        test_usize_align_down_to_containing_block
+    );
+
+    macro_rules! test_align_region_complement_impl {
+        ($u:ty, $align_region_complement:ident, $test_align_region_complement:ident) => {
+            #[test]
+            fn $test_align_region_complement() {
+                // sub-region in the middle
+                assert_eq!(
+                    $align_region_complement(0x10, 0x50, 0x20, 0x30),
+                    [(0x10, 0x20), (0x30, 0x50)]
+                );
+                // sub-region at the start
+                assert_eq!(
+                    $align_region_complement(0x10, 0x50, 0x10, 0x20),
+                    [(0x10, 0x10), (0x20, 0x50)]
+                );
+                // sub-region at the end
+                assert_eq!(
+                    $align_region_complement(0x10, 0x50, 0x40, 0x50),
+                    [(0x10, 0x40), (0x50, 0x50)]
+                );
+                // sub-region covers the region
+                assert_eq!(
+                    $align_region_complement(0x10, 0x50, 0, 0x60),
+                    [(0x10, 0x10), (0x50, 0x50)]
+                );
+                // disjoint sub-regions
+                assert_eq!(
+                    $align_region_complement(0x10, 0x50, 0, 0x8),
+                    [(0x10, 0x10), (0x10, 0x50)]
+                );
+                assert_eq!(
+                    $align_region_complement(0x10, 0x50, 0x60, 0x70),
+                    [(0x10, 0x50), (0x50, 0x50)]
+                );
+                // whole address space
+                assert_eq!(
+                    $align_region_complement(0, <$u>::MAX, 0x10, 0x20),
+                    [(0, 0x10), (0x20, <$u>::MAX)]
+                );
+            }
+        };
+    }
+
+    test_align_region_complement_impl!(
+        u8,
+        u8_align_region_complement,
+        test_u8_align_region_complement
+    );
+    test_align_region_complement_impl!(
+        u16,
+        u16_align_region_complement,
+        test_u16_align_region_complement
+    );
+    test_align_region_complement_impl!(
+        u32,
+        u32_align_region_complement,
+        test_u32_align_region_complement
+    );
+    test_align_region_complement_impl!(
+        u64,
+        u64_align_region_complement,
+        test_u64_align_region_complement
+    );
+    test_align_region_complement_impl!(
+        u128,
+        u128_align_region_complement,
+        test_u128_align_region_complement
+    );
+    test_align_region_complement_impl!(
+        usize,
+        usize_align_region_complement,
+        test_usize_align_region_complement
+    );
+
+    macro_rules! test_next_power_of_two_size_impl {
+        ($u:ty, $next_power_of_two_size:ident, $test_next_power_of_two_size:ident) => {
+            #[test]
+            fn $test_next_power_of_two_size() {
+                assert_eq!($next_power_of_two_size(0), Some(1));
+                assert_eq!($next_power_of_two_size(1), Some(1));
+                assert_eq!($next_power_of_two_size(3), Some(4));
+                assert_eq!($next_power_of_two_size(64), Some(64));
+                assert_eq!($next_power_of_two_size(65), Some(128));
+                assert_eq!(
+                    $next_power_of_two_size(<$u>::MAX / 2 + 1),
+                    Some(<$u>::MAX / 2 + 1)
+                );
+                assert_eq!($next_power_of_two_size(<$u>::MAX / 2 + 2), None);
+            }
+        };
+    }
+
+    test_next_power_of_two_size_impl!(
+        u8,
+        u8_next_power_of_two_size,
+        test_u8_next_power_of_two_size
+    );
+    test_next_power_of_two_size_impl!(
+        u16,
+        u16_next_power_of_two_size,
+        test_u16_next_power_of_two_size
+    );
+    test_next_power_of_two_size_impl!(
+        u32,
+        u32_next_power_of_two_size,
+        test_u32_next_power_of_two_size
+    );
+    test_next_power_of_two_size_impl!(
+        u64,
+        u64_next_power_of_two_size,
+        test_u64_next_power_of_two_size
+    );
+    test_next_power_of_two_size_impl!(
+        u128,
+        u128_next_power_of_two_size,
+        test_u128_next_power_of_two_size
+    );
+    test_next_power_of_two_size_impl!(
+        usize,
+        usize_next_power_of_two_size,
+        test_usize_next_power_of_two_size
+    );
+
+    macro_rules! test_is_naturally_aligned_impl {
+        ($u:ty, $is_naturally_aligned:ident, $test_is_naturally_aligned:ident) => {
+            #[test]
+            fn $test_is_naturally_aligned() {
+                assert!($is_naturally_aligned(0, 1));
+                assert!($is_naturally_aligned(0, 32));
+                assert!($is_naturally_aligned(0x40, 0x20));
+                assert!($is_naturally_aligned(0x60, 0x20));
+                assert!(!$is_naturally_aligned(0x50, 0x20));
+                assert!(!$is_naturally_aligned(<$u>::MAX, 2));
+            }
+        };
+    }
+
+    test_is_naturally_aligned_impl!(u8, u8_is_naturally_aligned, test_u8_is_naturally_aligned);
+    test_is_naturally_aligned_impl!(u16, u16_is_naturally_aligned, test_u16_is_naturally_aligned);
+    test_is_naturally_aligned_impl!(u32, u32_is_naturally_aligned, test_u32_is_naturally_aligned);
+    test_is_naturally_aligned_impl!(u64, u64_is_naturally_aligned, test_u64_is_naturally_aligned);
+    test_is_naturally_aligned_impl!(
+        u128,
+        u128_is_naturally_aligned,
+        test_u128_is_naturally_aligned
+    );
+    test_is_naturally_aligned_impl!(
+        usize,
+        usize_is_naturally_aligned,
+        test_usize_is_naturally_aligned
+    );
+
+    macro_rules! test_align_checked_const_impl {
+        ($u:ty, $align_down_checked_const:ident, $align_up_checked_const:ident, $test_align_checked_const:ident) => {
+            #[test]
+            fn $test_align_checked_const() {
+                assert_eq!($align_down_checked_const::<1>(123), 123);
+                assert_eq!($align_down_checked_const::<16>(123), 112);
+                assert_eq!($align_down_checked_const::<16>(<$u>::MAX), <$u>::MAX - 15);
+                assert_eq!($align_up_checked_const::<1>(123), 123);
+                assert_eq!($align_up_checked_const::<16>(123), 128);
+                assert_eq!($align_up_checked_const::<16>(0), 0);
+            }
+        };
+    }
+
+    test_align_checked_const_impl!(
+        u8,
+        u8_align_down_checked_const,
+        u8_align_up_checked_const,
+        test_u8_align_checked_const
+    );
+    test_align_checked_const_impl!(
+        u16,
+        u16_align_down_checked_const,
+        u16_align_up_checked_const,
+        test_u16_align_checked_const
+    );
+    test_align_checked_const_impl!(
+        u32,
+        u32_align_down_checked_const,
+        u32_align_up_checked_const,
+        test_u32_align_checked_const
+    );
+    test_align_checked_const_impl!(
+        u64,
+        u64_align_down_checked_const,
+        u64_align_up_checked_const,
+        test_u64_align_checked_const
+    );
+    test_align_checked_const_impl!(
+        u128,
+        u128_align_down_checked_const,
+        u128_align_up_checked_const,
+        test_u128_align_checked_const
+    );
+    test_align_checked_const_impl!(
+        usize,
+        usize_align_down_checked_const,
+        usize_align_up_checked_const,
+        test_usize_align_checked_const
+    );
+
+    macro_rules! test_align_region_find_gap_impl {
+        ($u:ty, $align_region_find_gap:ident, $test_align_region_find_gap:ident) => {
+            #[test]
+            fn $test_align_region_find_gap() {
+                let regions: [($u, $u); 4] = [(0, 0x10), (0x14, 0x21), (0x38, 0x40), (0x80, 0x80)];
+                assert_eq!($align_region_find_gap(&regions, 4, 4), Some(0x10));
+                assert_eq!($align_region_find_gap(&regions, 5, 8), Some(0x28));
+                assert_eq!($align_region_find_gap(&regions, 0x10, 8), Some(0x28));
+                assert_eq!($align_region_find_gap(&regions, 0x11, 8), Some(0x40));
+                assert_eq!($align_region_find_gap(&regions, 0x40, 0x40), Some(0x40));
+                assert_eq!($align_region_find_gap(&regions, 0x41, 1), None);
+                assert_eq!($align_region_find_gap(&regions[..1], 0, 1), None);
+                assert_eq!($align_region_find_gap(&[], 0, 1), None);
+                assert_eq!(
+                    $align_region_find_gap(&[(0, <$u>::MAX), (<$u>::MAX, <$u>::MAX)], 0, 2),
+                    None
+                );
+            }
+        };
+    }
+
+    test_align_region_find_gap_impl!(u8, u8_align_region_find_gap, test_u8_align_region_find_gap);
+    test_align_region_find_gap_impl!(
+        u16,
+        u16_align_region_find_gap,
+        test_u16_align_region_find_gap
+    );
+    test_align_region_find_gap_impl!(
+        u32,
+        u32_align_region_find_gap,
+        test_u32_align_region_find_gap
+    );
+    test_align_region_find_gap_impl!(
+        u64,
+        u64_align_region_find_gap,
+        test_u64_align_region_find_gap
+    );
+    test_align_region_find_gap_impl!(
+        u128,
+        u128_align_region_find_gap,
+        test_u128_align_region_find_gap
+    );
+    test_align_region_find_gap_impl!(
+        usize,
+        usize_align_region_find_gap,
+        test_usize_align_region_find_gap
+    );
+
+    macro_rules! test_align_up_table_entry_impl {
+        ($u:ty, $align_up_table_entry:ident, $test_align_up_table_entry:ident) => {
+            #[test]
+            fn $test_align_up_table_entry() {
+                assert_eq!($align_up_table_entry(0x40, 0, 8), Some(0x40));
+                assert_eq!($align_up_table_entry(0x40, 3, 8), Some(0x58));
+                assert_eq!($align_up_table_entry(0x40, 3, 1), Some(0x43));
+                assert_eq!($align_up_table_entry(0, <$u>::MAX, 1), Some(<$u>::MAX));
+                assert_eq!($align_up_table_entry(1, <$u>::MAX, 1), None);
+                assert_eq!($align_up_table_entry(0, <$u>::MAX, 2), None);
+            }
+        };
+    }
+
+    test_align_up_table_entry_impl!(u8, u8_align_up_table_entry, test_u8_align_up_table_entry);
+    test_align_up_table_entry_impl!(u16, u16_align_up_table_entry, test_u16_align_up_table_entry);
+    test_align_up_table_entry_impl!(u32, u32_align_up_table_entry, test_u32_align_up_table_entry);
+    test_align_up_table_entry_impl!(u64, u64_align_up_table_entry, test_u64_align_up_table_entry);
+    test_align_up_table_entry_impl!(
+        u128,
+        u128_align_up_table_entry,
+        test_u128_align_up_table_entry
+    );
+    test_align_up_table_entry_impl!(
+        usize,
+        usize_align_up_table_entry,
+        test_usize_align_up_table_entry
+    );
+
+    macro_rules! test_align_up_with_offset_check_impl {
+        ($u:ty, $align_up_with_offset_check:ident, $test_align_up_with_offset_check:ident) => {
+            #[test]
+            fn $test_align_up_with_offset_check() {
+                assert_eq!($align_up_with_offset_check(16, 8, 0), Some(16));
+                assert_eq!($align_up_with_offset_check(13, 8, 3), Some(16));
+                assert_eq!($align_up_with_offset_check(13, 8, 2), None);
+                assert_eq!($align_up_with_offset_check(9, 8, <$u>::MAX), Some(16));
+                assert_eq!($align_up_with_offset_check(<$u>::MAX, 2, <$u>::MAX), None);
+            }
+        };
+    }
+
+    test_align_up_with_offset_check_impl!(
+        u8,
+        u8_align_up_with_offset_check,
+        test_u8_align_up_with_offset_check
+    );
+    test_align_up_with_offset_check_impl!(
+        u16,
+        u16_align_up_with_offset_check,
+        test_u16_align_up_with_offset_check
+    );
+    test_align_up_with_offset_check_impl!(
+        u32,
+        u32_align_up_with_offset_check,
+        test_u32_align_up_with_offset_check
+    );
+    test_align_up_with_offset_check_impl!(
+        u64,
+        u64_align_up_with_offset_check,
+        test_u64_align_up_with_offset_check
+    );
+    test_align_up_with_offset_check_impl!(
+        u128,
+        u128_align_up_with_offset_check,
+        test_u128_align_up_with_offset_check
+    );
+    test_align_up_with_offset_check_impl!(
+        usize,
+        usize_align_up_with_offset_check,
+        test_usize_align_up_with_offset_check
+    );
+
+    macro_rules! test_align_up_report_impl {
+        ($u:ty, $align_up_report:ident, $test_align_up_report:ident) => {
+            #[test]
+            fn $test_align_up_report() {
+                assert_eq!($align_up_report(123, 4), (124, 1));
+                assert_eq!($align_up_report(124, 4), (124, 0));
+                assert_eq!($align_up_report(0, 64), (0, 0));
+                assert_eq!($align_up_report(1, 64), (64, 63));
+                assert_eq!($align_up_report(<$u>::MAX, 1), (<$u>::MAX, 0));
+            }
+        };
+    }
+
+    test_align_up_report_impl!(u8, u8_align_up_report, test_u8_align_up_report);
+    test_align_up_report_impl!(u16, u16_align_up_report, test_u16_align_up_report);
+    test_align_up_report_impl!(u32, u32_align_up_report, test_u32_align_up_report);
+    test_align_up_report_impl!(u64, u64_align_up_report, test_u64_align_up_report);
+    test_align_up_report_impl!(u128, u128_align_up_report, test_u128_align_up_report);
+    test_align_up_report_impl!(usize, usize_align_up_report, test_usize_align_up_report);
 }